@@ -0,0 +1,307 @@
+//! # Argumentos
+//! `argumentos` es un submódulo que contiene a [`Argumentos`], el reconocimiento de los argumentos de un subcomando de la línea de comandos.
+
+use crate::error::error_io::ErrorIO;
+use crate::my_io::RUTA_ESTANDAR;
+use crate::tablero::builder::TableroBuilder;
+use crate::tablero::coordenada::Coordenadas2D;
+use crate::tablero::simbolos::Simbolos;
+use crate::tablero::topologia::Topologia;
+use crate::tablero::vecindario::Vecindario;
+use std::str::FromStr;
+
+/// Nombres de la bandera que muestra la ayuda de un subcomando. Todos los subcomandos la aceptan.
+pub const AYUDA: [&str; 2] = ["-h", "--help"];
+/// Nombres de la opción que indica el archivo de salida.
+pub const SALIDA: [&str; 2] = ["-o", "--output"];
+/// Nombre de la opción que indica la [`Topologia`] del tablero.
+pub const TOPOLOGIA: [&str; 1] = ["--topologia"];
+/// Nombre de la opción que indica el [`Vecindario`] del tablero.
+pub const VECINDARIO: [&str; 1] = ["--vecindario"];
+/// Nombre de la opción que indica los [`Simbolos`] con los que se lee el tablero.
+pub const SIMBOLOS: [&str; 1] = ["--simbolos"];
+/// Nombre de la bandera que indica que las filas más cortas se completan con paredes.
+pub const RELLENO: [&str; 1] = ["--relleno"];
+
+#[derive(Debug, Default, PartialEq, Eq)]
+/// Estructura que contiene los argumentos de un subcomando separados en argumentos posicionales, opciones con valor y banderas.
+///
+/// Las opciones aceptan su valor como el argumento siguiente (`--minas 10`) o unido por un `=` (`--minas=10`). El argumento `-` se considera posicional, ya que representa a la [entrada estándar][RUTA_ESTANDAR].
+pub struct Argumentos {
+    /// Argumentos que no son opciones ni banderas, en el orden en que se recibieron.
+    posicionales: Vec<String>,
+    /// Opciones recibidas junto con su valor.
+    opciones: Vec<(String, String)>,
+    /// Banderas recibidas.
+    banderas: Vec<String>,
+}
+
+impl Argumentos {
+    /// Reconoce los `args` de un subcomando que acepta las `opciones` y las `banderas` indicadas, además de las de [ayuda][AYUDA].
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::cli::argumentos::{Argumentos, SALIDA};
+    /// # use buscaminas::error::error_io::ErrorIO;
+    /// #
+    /// # fn main() -> Result<(),ErrorIO> {
+    /// let args = ["tablero.txt", "--output=solucion.txt"].map(String::from);
+    /// let argumentos = Argumentos::parsear(&args, &SALIDA, &[])?;
+    /// assert_eq!(argumentos.posicionales(), ["tablero.txt"]);
+    /// assert_eq!(argumentos.salida(), "solucion.txt");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] si algún argumento es una opción o una bandera desconocida, o si falta el valor de una opción.
+    pub fn parsear(
+        args: &[String],
+        opciones: &[&str],
+        banderas: &[&str],
+    ) -> Result<Argumentos, ErrorIO> {
+        let mut argumentos = Argumentos::default();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let (nombre, valor) = match arg.split_once('=') {
+                Some((nombre, valor)) if nombre.starts_with("--") => (nombre, Some(valor)),
+                _ => (arg.as_str(), None),
+            };
+            if !nombre.starts_with('-') || nombre == RUTA_ESTANDAR {
+                argumentos.posicionales.push(arg.clone());
+            } else if opciones.contains(&nombre) {
+                let valor = valor.or_else(|| args.next().map(String::as_str));
+                let valor =
+                    valor.ok_or_else(|| invalido(format!("Falta el valor de {}", nombre)))?;
+                argumentos
+                    .opciones
+                    .push((nombre.to_string(), valor.to_string()));
+            } else if (banderas.contains(&nombre) || AYUDA.contains(&nombre)) && valor.is_none() {
+                argumentos.banderas.push(nombre.to_string());
+            } else {
+                return Err(invalido(format!("Opción desconocida: {}", arg)));
+            }
+        }
+        Ok(argumentos)
+    }
+
+    /// Retorna los argumentos posicionales.
+    pub fn posicionales(&self) -> &[String] {
+        &self.posicionales
+    }
+
+    /// Retorna el único argumento posicional, que indica el archivo de entrada.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] si no se recibió exactamente un argumento posicional.
+    pub fn archivo(&self) -> Result<&str, ErrorIO> {
+        match self.posicionales.as_slice() {
+            [archivo] => Ok(archivo),
+            _ => Err(invalido(String::from("Se esperaba un único archivo"))),
+        }
+    }
+
+    /// Retorna el valor de la opción con alguno de los `nombres` indicados, o `None` si no se recibió. Si se recibió más de una vez, se retorna el último valor.
+    pub fn opcion(&self, nombres: &[&str]) -> Option<&str> {
+        self.opciones
+            .iter()
+            .rev()
+            .find(|(nombre, _)| nombres.contains(&nombre.as_str()))
+            .map(|(_, valor)| valor.as_str())
+    }
+
+    /// Retorna `true` si se recibió la bandera con alguno de los `nombres` indicados.
+    pub fn bandera(&self, nombres: &[&str]) -> bool {
+        self.banderas.iter().any(|b| nombres.contains(&b.as_str()))
+    }
+
+    /// Retorna la ruta de [salida][SALIDA], que por defecto es la [salida estándar][RUTA_ESTANDAR].
+    pub fn salida(&self) -> &str {
+        self.opcion(&SALIDA).unwrap_or(RUTA_ESTANDAR)
+    }
+
+    /// Retorna el valor numérico de la opción con alguno de los `nombres` indicados, o `por_defecto` si no se recibió.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] si el valor no es un número válido.
+    pub fn numero<T: FromStr>(&self, nombres: &[&str], por_defecto: T) -> Result<T, ErrorIO> {
+        match self.opcion(nombres) {
+            Some(valor) => parsear_numero(valor),
+            None => Ok(por_defecto),
+        }
+    }
+
+    /// Retorna la coordenada de la opción con alguno de los `nombres` indicados, escrita como `x,y`, o `None` si no se recibió.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] si el valor no tiene el formato `x,y`.
+    pub fn coordenada(&self, nombres: &[&str]) -> Result<Option<Coordenadas2D>, ErrorIO> {
+        self.opcion(nombres).map(parsear_coordenada).transpose()
+    }
+
+    /// Retorna el `builder` configurado con la [topología][TOPOLOGIA], el [vecindario][VECINDARIO], los [símbolos][SIMBOLOS] y el [relleno][RELLENO] recibidos.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] si alguno de los valores no es válido.
+    pub fn configurar<'a>(
+        &self,
+        builder: TableroBuilder<'a>,
+    ) -> Result<TableroBuilder<'a>, ErrorIO> {
+        let builder = builder
+            .con_topologia(self.topologia()?)
+            .con_vecindario(self.vecindario()?)
+            .con_relleno(self.bandera(&RELLENO));
+        Ok(match self.opcion(&SIMBOLOS) {
+            Some(simbolos) => builder
+                .con_simbolos(parsear_simbolos(simbolos)?)
+                .map_err(|e| invalido(format!("{:?}", e)))?,
+            None => builder,
+        })
+    }
+
+    /// Retorna la [`Topologia`] indicada con la [opción][TOPOLOGIA] correspondiente: `acotada` (por defecto) o `toroidal`.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] si el nombre no corresponde a ninguna topología.
+    pub fn topologia(&self) -> Result<Topologia, ErrorIO> {
+        match self.opcion(&TOPOLOGIA) {
+            None | Some("acotada") => Ok(Topologia::Acotada),
+            Some("toroidal") => Ok(Topologia::Toroidal),
+            Some(otra) => Err(invalido(format!("Topología desconocida: {}", otra))),
+        }
+    }
+
+    /// Retorna el [`Vecindario`] indicado con la [opción][VECINDARIO] correspondiente: `moore` (por defecto), `von-neumann`, `caballo` o `hexagonal`.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] si el nombre no corresponde a ningún vecindario.
+    pub fn vecindario(&self) -> Result<Vecindario, ErrorIO> {
+        match self.opcion(&VECINDARIO) {
+            None | Some("moore") => Ok(Vecindario::Moore),
+            Some("von-neumann") => Ok(Vecindario::VonNeumann),
+            Some("caballo") => Ok(Vecindario::Caballo),
+            Some("hexagonal") => Ok(Vecindario::Hexagonal),
+            Some(otro) => Err(invalido(format!("Vecindario desconocido: {}", otro))),
+        }
+    }
+}
+
+/// Reconoce una coordenada escrita como `x,y`.
+///
+/// # Errores
+///
+/// Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] si el texto no tiene ese formato.
+pub fn parsear_coordenada(texto: &str) -> Result<Coordenadas2D, ErrorIO> {
+    let error = || invalido(format!("{} no es una coordenada x,y", texto));
+    let (x, y) = texto.split_once(',').ok_or_else(error)?;
+    match (x.trim().parse(), y.trim().parse()) {
+        (Ok(x), Ok(y)) => Ok(Coordenadas2D::new(x, y)),
+        _ => Err(error()),
+    }
+}
+
+/// Reconoce un número del tipo `T` escrito en el `texto`.
+///
+/// # Errores
+///
+/// Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] si el texto no es un número válido.
+pub fn parsear_numero<T: FromStr>(texto: &str) -> Result<T, ErrorIO> {
+    texto
+        .parse()
+        .map_err(|_| invalido(format!("{} no es un número válido", texto)))
+}
+
+/// Reconoce un conjunto de [`Simbolos`] escrito como una lista de pares `tipo=caracter` separados por comas, por ejemplo `mina=X,espacio=0`. Los tipos que no se indican conservan su [caracter por defecto][Simbolos::default].
+///
+/// Los tipos aceptados son `mina`, `mina-doble`, `mina-triple`, `antimina`, `pared`, `espacio`, `oculta` y `marca`.
+///
+/// # Errores
+///
+/// Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] si algún par no tiene ese formato, si el tipo es desconocido, si el valor no es un único caracter o si los símbolos resultantes se repiten o usan caracteres reservados.
+pub fn parsear_simbolos(texto: &str) -> Result<Simbolos, ErrorIO> {
+    let mut simbolos = Simbolos::default();
+    for par in texto.split(',') {
+        let error = || invalido(format!("{} no es un par tipo=caracter", par));
+        let (tipo, valor) = par.split_once('=').ok_or_else(error)?;
+        let mut caracteres = valor.chars();
+        let caracter = match (caracteres.next(), caracteres.next()) {
+            (Some(caracter), None) => caracter,
+            _ => return Err(error()),
+        };
+        let campo = match tipo.trim() {
+            "mina" => &mut simbolos.mina,
+            "mina-doble" => &mut simbolos.mina_doble,
+            "mina-triple" => &mut simbolos.mina_triple,
+            "antimina" => &mut simbolos.antimina,
+            "pared" => &mut simbolos.pared,
+            "espacio" => &mut simbolos.espacio,
+            "oculta" => &mut simbolos.oculta,
+            "marca" => &mut simbolos.marca,
+            _ => return Err(error()),
+        };
+        *campo = caracter;
+    }
+    simbolos.validar().map_err(|_| {
+        invalido(format!(
+            "{} repite caracteres o usa caracteres reservados",
+            texto
+        ))
+    })?;
+    Ok(simbolos)
+}
+
+/// Construye un [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] con la `descripcion` dada.
+pub(crate) fn invalido(descripcion: String) -> ErrorIO {
+    ErrorIO::ArgumentoInvalido(descripcion)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsear(args: &[&str]) -> Result<Argumentos, ErrorIO> {
+        let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+        Argumentos::parsear(&args, &["-o", "--output", "--minas"], &["--relleno"])
+    }
+
+    #[test]
+    fn separar_posicionales_opciones_y_banderas() {
+        let argumentos = parsear(&["-", "--minas", "3", "--relleno", "x.txt"]).unwrap();
+        assert_eq!(argumentos.posicionales(), ["-", "x.txt"]);
+        assert_eq!(argumentos.numero(&["--minas"], 0).unwrap(), 3);
+        assert!(argumentos.bandera(&RELLENO));
+        assert!(!argumentos.bandera(&AYUDA));
+        assert_eq!(argumentos.salida(), RUTA_ESTANDAR);
+    }
+
+    #[test]
+    fn opciones_invalidas() {
+        assert!(parsear(&["--desconocida"]).is_err());
+        assert!(parsear(&["--minas"]).is_err());
+        assert!(parsear(&["--relleno=si"]).is_err());
+        let argumentos = parsear(&["--minas=muchas"]).unwrap();
+        assert!(argumentos.numero(&["--minas"], 0usize).is_err());
+    }
+
+    #[test]
+    fn parsear_coordenadas_y_simbolos() {
+        assert_eq!(parsear_coordenada("3,4").unwrap(), Coordenadas2D::new(3, 4));
+        assert!(parsear_coordenada("3").is_err());
+        let simbolos = parsear_simbolos("mina=💣,espacio=0").unwrap();
+        assert_eq!((simbolos.mina, simbolos.espacio), ('💣', '0'));
+        assert_eq!(simbolos.pared, Simbolos::default().pared);
+        assert!(parsear_simbolos("mina=XY").is_err());
+        assert!(parsear_simbolos("bomba=X").is_err());
+        assert!(parsear_simbolos("mina=#").is_err());
+        assert!(parsear_simbolos("pared=8").is_err());
+        assert!(parsear_simbolos("espacio=-").is_err());
+        assert!(parsear_simbolos("mina= ").is_err());
+    }
+}
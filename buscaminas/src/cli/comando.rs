@@ -0,0 +1,137 @@
+//! # Comando
+//! `comando` es un submódulo que contiene a [`Comando`], los subcomandos que acepta el binario.
+
+use crate::cli::argumentos::{Argumentos, AYUDA};
+use crate::cli::{convertir, estadisticas, generar, jugar, pista, resolver, validar};
+use crate::error::Error;
+use crate::my_io::escritor::Escritor;
+use crate::my_io::RUTA_ESTANDAR;
+use std::process::ExitCode;
+
+/// Todos los subcomandos, en el orden en que se listan en la ayuda.
+pub const COMANDOS: [Comando; 7] = [
+    Comando::Resolver,
+    Comando::Generar,
+    Comando::Jugar,
+    Comando::Validar,
+    Comando::Pista,
+    Comando::Convertir,
+    Comando::Estadisticas,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// `enum` cuyas variantes representan los subcomandos del binario. Cada uno reconoce sus propias opciones y muestra su ayuda con `--help`.
+pub enum Comando {
+    /// Variante que resuelve un tablero y escribe su solución.
+    Resolver,
+    /// Variante que genera un tablero aleatorio.
+    Generar,
+    /// Variante que permite jugar una partida desde la terminal.
+    Jugar,
+    /// Variante que verifica si un problema parcial tiene solución única.
+    Validar,
+    /// Variante que indica qué celdas de un tablero parcial pueden descubrirse.
+    Pista,
+    /// Variante que reescribe un tablero con otros símbolos.
+    Convertir,
+    /// Variante que escribe las estadísticas de un tablero.
+    Estadisticas,
+}
+
+impl Comando {
+    /// Retorna el [`Comando`] cuyo nombre es `nombre`, o `None` si no existe.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::cli::comando::Comando;
+    /// #
+    /// # fn main() {
+    /// assert_eq!(Comando::identificar("generar"), Some(Comando::Generar));
+    /// assert_eq!(Comando::identificar("tablero.txt"), None);
+    /// # }
+    /// ```
+    pub fn identificar(nombre: &str) -> Option<Comando> {
+        COMANDOS.into_iter().find(|c| c.nombre() == nombre)
+    }
+
+    /// Retorna el nombre con el que se invoca el subcomando.
+    pub fn nombre(&self) -> &'static str {
+        match self {
+            Comando::Resolver => "resolver",
+            Comando::Generar => "generar",
+            Comando::Jugar => "jugar",
+            Comando::Validar => "validar",
+            Comando::Pista => "pista",
+            Comando::Convertir => "convertir",
+            Comando::Estadisticas => "estadisticas",
+        }
+    }
+
+    /// Retorna una descripción breve del subcomando, utilizada en la ayuda general.
+    pub fn descripcion(&self) -> &'static str {
+        match self {
+            Comando::Resolver => "Resuelve un tablero y escribe su solución.",
+            Comando::Generar => "Genera un tablero aleatorio.",
+            Comando::Jugar => "Juega una partida desde la terminal.",
+            Comando::Validar => "Verifica si un problema parcial tiene solución única.",
+            Comando::Pista => "Indica qué celdas de un tablero parcial pueden descubrirse.",
+            Comando::Convertir => "Reescribe un tablero con otros símbolos.",
+            Comando::Estadisticas => "Escribe las estadísticas de un tablero.",
+        }
+    }
+
+    /// Retorna el texto de ayuda del subcomando, con su uso y sus opciones.
+    pub fn ayuda(&self) -> &'static str {
+        match self {
+            Comando::Resolver => resolver::AYUDA,
+            Comando::Generar => generar::AYUDA,
+            Comando::Jugar => jugar::AYUDA,
+            Comando::Validar => validar::AYUDA,
+            Comando::Pista => pista::AYUDA,
+            Comando::Convertir => convertir::AYUDA,
+            Comando::Estadisticas => estadisticas::AYUDA,
+        }
+    }
+
+    /// Ejecuta el subcomando con los `args` que le siguen en la línea de comandos. Si entre ellos se encuentra la bandera de [ayuda][AYUDA], solo se muestra la ayuda.
+    ///
+    /// # Errores
+    ///
+    /// Retorna los errores producidos al reconocer los argumentos o al ejecutar el subcomando.
+    pub fn ejecutar(&self, args: &[String]) -> Result<ExitCode, Error> {
+        let (opciones, banderas) = self.opciones();
+        let argumentos = Argumentos::parsear(args, &opciones, banderas)?;
+        if argumentos.bandera(&AYUDA) {
+            Escritor::new(RUTA_ESTANDAR).imprimir_item(&self.ayuda())?;
+            return Ok(ExitCode::SUCCESS);
+        }
+        match self {
+            Comando::Resolver => resolver::ejecutar(&argumentos),
+            Comando::Generar => generar::ejecutar(&argumentos),
+            Comando::Jugar => jugar::ejecutar(&argumentos),
+            Comando::Validar => validar::ejecutar(&argumentos),
+            Comando::Pista => pista::ejecutar(&argumentos),
+            Comando::Convertir => convertir::ejecutar(&argumentos),
+            Comando::Estadisticas => estadisticas::ejecutar(&argumentos),
+        }
+    }
+
+    /// Retorna las opciones y las banderas que acepta el subcomando.
+    fn opciones(&self) -> (Vec<&'static str>, &'static [&'static str]) {
+        match self {
+            Comando::Resolver => (resolver::OPCIONES.to_vec(), &resolver::BANDERAS),
+            Comando::Generar => (
+                [&generar::OPCIONES_GENERACION[..], &generar::OPCIONES].concat(),
+                &generar::BANDERAS,
+            ),
+            Comando::Jugar => (
+                [&generar::OPCIONES_GENERACION[..], &jugar::OPCIONES].concat(),
+                &jugar::BANDERAS,
+            ),
+            Comando::Validar => (validar::OPCIONES.to_vec(), &validar::BANDERAS),
+            Comando::Pista => (pista::OPCIONES.to_vec(), &pista::BANDERAS),
+            Comando::Convertir => (convertir::OPCIONES.to_vec(), &convertir::BANDERAS),
+            Comando::Estadisticas => (estadisticas::OPCIONES.to_vec(), &estadisticas::BANDERAS),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! # Convertir
+//! `convertir` es el subcomando que reescribe un tablero con otro conjunto de [`Simbolos`].
+
+use crate::cli::argumentos::{parsear_simbolos, Argumentos};
+use crate::error::Error;
+use crate::my_io::escritor::Escritor;
+use crate::my_io::lector::Lector;
+use crate::tablero::simbolos::Simbolos;
+use std::process::ExitCode;
+
+/// Texto de ayuda del subcomando.
+pub(crate) const AYUDA: &str = "\
+Uso: buscaminas convertir <archivo> [opciones]
+
+Reescribe el tablero del archivo, resuelto o no, con otro conjunto de símbolos. Los
+símbolos se indican como una lista de pares tipo=caracter, donde el tipo es mina,
+mina-doble, mina-triple, antimina, pared, espacio, oculta o marca. Los tipos que no se
+indican conservan su símbolo por defecto.
+
+Opciones:
+  --de <lista>                 Símbolos con los que está escrito el tablero.
+  --a <lista>                  Símbolos con los que se reescribe el tablero.
+  -o, --output <archivo>       Archivo de salida. Por defecto, la salida estándar.
+  -h, --help                   Muestra esta ayuda.
+";
+/// Nombre de la opción que indica los símbolos de origen.
+const DE: [&str; 1] = ["--de"];
+/// Nombre de la opción que indica los símbolos de destino.
+const A: [&str; 1] = ["--a"];
+/// Opciones que acepta el subcomando.
+pub(crate) const OPCIONES: [&str; 4] = [DE[0], A[0], "-o", "--output"];
+/// Banderas que acepta el subcomando.
+pub(crate) const BANDERAS: [&str; 0] = [];
+
+/// Reescribe el tablero del archivo indicado en los `argumentos` con los símbolos de destino y lo escribe en la salida indicada.
+///
+/// # Errores
+///
+/// Retorna los errores producidos al reconocer los símbolos, al leer el archivo, al convertirlo o al escribirlo.
+pub(crate) fn ejecutar(argumentos: &Argumentos) -> Result<ExitCode, Error> {
+    let origen = simbolos(argumentos, &DE)?;
+    let destino = simbolos(argumentos, &A)?;
+    let texto = Lector::new(argumentos.archivo()?).leer_archivo()?;
+    let convertido = origen.convertir(&texto, &destino)?;
+    Escritor::new(argumentos.salida()).imprimir_item(&convertido)?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Retorna los [`Simbolos`] de la opción con alguno de los `nombres` indicados, o los [símbolos por defecto][Simbolos::default] si no se recibió.
+fn simbolos(argumentos: &Argumentos, nombres: &[&str]) -> Result<Simbolos, Error> {
+    match argumentos.opcion(nombres) {
+        Some(texto) => Ok(parsear_simbolos(texto)?),
+        None => Ok(Simbolos::default()),
+    }
+}
@@ -0,0 +1,53 @@
+//! # Estadisticas
+//! `estadisticas` es el subcomando que resuelve un tablero y escribe sus [`Estadisticas`].
+//!
+//! [`Estadisticas`]: crate::tablero::estadisticas::Estadisticas
+
+use crate::cli::argumentos::Argumentos;
+use crate::error::Error;
+use crate::my_io::escritor::Escritor;
+use crate::my_io::lector::Lector;
+use crate::tablero::builder::TableroBuilder;
+use std::process::ExitCode;
+
+/// Texto de ayuda del subcomando.
+pub(crate) const AYUDA: &str = "\
+Uso: buscaminas estadisticas <archivo> [opciones]
+
+Resuelve el tablero del archivo, de dos o tres dimensiones, y escribe la cantidad de
+celdas de cada tipo, la mayor cantidad de minas adyacentes y la densidad de minas.
+
+Opciones:
+  -o, --output <archivo>       Archivo de salida. Por defecto, la salida estándar.
+  --topologia <nombre>         acotada (por defecto) o toroidal.
+  --vecindario <nombre>        moore (por defecto), von-neumann, caballo o hexagonal.
+  --simbolos <lista>           Símbolos del tablero, por ejemplo mina=X,espacio=0.
+  --relleno                    Completa las filas más cortas con paredes.
+  -h, --help                   Muestra esta ayuda.
+";
+/// Opciones que acepta el subcomando.
+pub(crate) const OPCIONES: [&str; 5] = [
+    "-o",
+    "--output",
+    "--topologia",
+    "--vecindario",
+    "--simbolos",
+];
+/// Banderas que acepta el subcomando.
+pub(crate) const BANDERAS: [&str; 1] = ["--relleno"];
+
+/// Resuelve el tablero del archivo indicado en los `argumentos` y escribe sus estadísticas en la salida indicada.
+///
+/// # Errores
+///
+/// Retorna los errores producidos al leer el archivo, al construir el tablero o al escribir las estadísticas.
+pub(crate) fn ejecutar(argumentos: &Argumentos) -> Result<ExitCode, Error> {
+    let casilleros = Lector::new(argumentos.archivo()?).leer_archivo()?;
+    let builder = argumentos.configurar(TableroBuilder::new(&casilleros)?)?;
+    let estadisticas = match builder.es_tridimensional() {
+        true => builder.crear_tablero_3d()?.estadisticas(),
+        false => builder.crear_tablero()?.estadisticas(),
+    };
+    Escritor::new(argumentos.salida()).imprimir_item(&estadisticas)?;
+    Ok(ExitCode::SUCCESS)
+}
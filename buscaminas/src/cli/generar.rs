@@ -0,0 +1,215 @@
+//! # Generar
+//! `generar` es el subcomando que genera un tablero aleatorio.
+
+use crate::cli::argumentos::{invalido, Argumentos};
+use crate::error::error_io::ErrorIO;
+use crate::error::Error;
+use crate::my_io::escritor::Escritor;
+use crate::tablero::coordenada::Coordenadas2D;
+use crate::tablero::generador::Generador;
+use crate::tablero::Tablero;
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Texto de ayuda del subcomando.
+pub(crate) const AYUDA: &str = "\
+Uso: buscaminas generar [opciones]
+
+Genera un tablero aleatorio y escribe el problema, listo para resolverse.
+
+Opciones:
+  --ancho <n>                  Ancho del tablero. Por defecto, 9.
+  --largo <n>                  Largo del tablero. Por defecto, 9.
+  --minas <n>                  Cantidad de minas. Por defecto, 10.
+  --densidad <d>               Proporción de minas entre 0 y 1, en lugar de --minas.
+                               No se combina con --primer-click ni --sin-adivinar.
+  --semilla <n>                Semilla del generador. Por defecto, se toma de la hora actual.
+  --primer-click <x,y>         Celda que, junto a sus adyacentes, no contiene minas.
+  --sin-adivinar               Garantiza que el tablero se resuelve sin adivinar desde
+                               el primer click (por defecto, 0,0).
+  --resuelto                   Escribe el tablero resuelto en lugar del problema.
+  --topologia <nombre>         acotada (por defecto) o toroidal.
+  --vecindario <nombre>        moore (por defecto), von-neumann, caballo o hexagonal.
+  -o, --output <archivo>       Archivo de salida. Por defecto, la salida estándar.
+  -h, --help                   Muestra esta ayuda.
+";
+/// Nombre de la opción que indica el ancho del tablero.
+const ANCHO: [&str; 1] = ["--ancho"];
+/// Nombre de la opción que indica el largo del tablero.
+const LARGO: [&str; 1] = ["--largo"];
+/// Nombre de la opción que indica la cantidad de minas.
+const MINAS: [&str; 1] = ["--minas"];
+/// Nombre de la opción que indica la densidad de minas.
+const DENSIDAD: [&str; 1] = ["--densidad"];
+/// Nombre de la opción que indica la semilla del generador.
+const SEMILLA: [&str; 1] = ["--semilla"];
+/// Nombre de la opción que indica el primer click.
+const PRIMER_CLICK: [&str; 1] = ["--primer-click"];
+/// Nombre de la bandera que indica que el tablero debe resolverse sin adivinar.
+const SIN_ADIVINAR: [&str; 1] = ["--sin-adivinar"];
+/// Nombre de la bandera que indica que se escribe el tablero resuelto.
+const RESUELTO: [&str; 1] = ["--resuelto"];
+/// Opciones que se utilizan para generar un tablero, compartidas con el subcomando [jugar](super::jugar).
+pub(crate) const OPCIONES_GENERACION: [&str; 8] = [
+    ANCHO[0],
+    LARGO[0],
+    MINAS[0],
+    DENSIDAD[0],
+    SEMILLA[0],
+    PRIMER_CLICK[0],
+    "--topologia",
+    "--vecindario",
+];
+/// Opciones y banderas de [generación][OPCIONES_GENERACION] que no se aplican a un tablero leído de un archivo, a diferencia de la topología y el vecindario.
+const SOLO_GENERACION: [&str; 7] = [
+    ANCHO[0],
+    LARGO[0],
+    MINAS[0],
+    DENSIDAD[0],
+    SEMILLA[0],
+    PRIMER_CLICK[0],
+    SIN_ADIVINAR[0],
+];
+/// Opciones que acepta el subcomando, además de las de [generación][OPCIONES_GENERACION].
+pub(crate) const OPCIONES: [&str; 2] = ["-o", "--output"];
+/// Banderas que acepta el subcomando.
+pub(crate) const BANDERAS: [&str; 2] = [SIN_ADIVINAR[0], RESUELTO[0]];
+
+/// Genera un tablero con las opciones indicadas en los `argumentos` y escribe el problema, o el tablero resuelto si así se indica, en la salida indicada.
+///
+/// # Errores
+///
+/// Retorna los errores producidos al generar el tablero o al escribirlo.
+pub(crate) fn ejecutar(argumentos: &Argumentos) -> Result<ExitCode, Error> {
+    let tablero = generar(argumentos)?;
+    let escritor = Escritor::new(argumentos.salida());
+    match argumentos.bandera(&RESUELTO) {
+        true => escritor.imprimir_item(&tablero)?,
+        false => escritor.imprimir_item(&tablero.problema())?,
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Genera un tablero **resuelto** con las [opciones de generación][OPCIONES_GENERACION] indicadas en los `argumentos`.
+///
+/// # Errores
+///
+/// - Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] si se indicó la densidad junto con el primer click o la generación sin adivinar.
+/// - Retorna los errores producidos al reconocer las opciones o al generar el tablero.
+pub(crate) fn generar(argumentos: &Argumentos) -> Result<Tablero, Error> {
+    verificar_densidad(argumentos)?;
+    let generador = Generador::new(
+        argumentos.numero(&ANCHO, 9)?,
+        argumentos.numero(&LARGO, 9)?,
+        argumentos.numero(&SEMILLA, semilla_por_defecto())?,
+    )?
+    .con_topologia(argumentos.topologia()?)
+    .con_vecindario(argumentos.vecindario()?);
+    let minas = argumentos.numero(&MINAS, 10)?;
+    let tablero = match (argumentos.opcion(&DENSIDAD), primer_click(argumentos)?) {
+        (Some(_), _) => generador.generar_con_densidad(argumentos.numero(&DENSIDAD, 0.0)?)?,
+        (None, Some(click)) if argumentos.bandera(&SIN_ADIVINAR) => {
+            generador.generar_sin_adivinar(minas, &click)?
+        }
+        (None, Some(click)) => generador.generar_con_primer_click(minas, &click, true)?,
+        (None, None) => generador.generar_con_minas(minas)?,
+    };
+    Ok(tablero)
+}
+
+/// Verifica que la [densidad][DENSIDAD] no se indique junto con el [primer click][PRIMER_CLICK] o la [generación sin adivinar][SIN_ADIVINAR], ya que un tablero generado por densidad no admite ninguna de las dos.
+///
+/// # Errores
+///
+/// Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] si se indicaron juntas.
+fn verificar_densidad(argumentos: &Argumentos) -> Result<(), ErrorIO> {
+    let con_click = argumentos.opcion(&PRIMER_CLICK).is_some() || argumentos.bandera(&SIN_ADIVINAR);
+    match argumentos.opcion(&DENSIDAD) {
+        Some(_) if con_click => Err(invalido(String::from(
+            "--densidad no puede combinarse con --primer-click ni con --sin-adivinar",
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Retorna la celda del [primer click][PRIMER_CLICK] indicada en los `argumentos` o, si se pidió un tablero [sin adivinar][SIN_ADIVINAR] sin indicarla, la celda `(0, 0)`. Un tablero generado con ellos no tiene minas en esa celda ni en sus adyacentes.
+///
+/// # Errores
+///
+/// Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] si la coordenada no es válida.
+pub(crate) fn primer_click(argumentos: &Argumentos) -> Result<Option<Coordenadas2D>, ErrorIO> {
+    let click = argumentos.coordenada(&PRIMER_CLICK)?;
+    match argumentos.bandera(&SIN_ADIVINAR) {
+        true => Ok(Some(click.unwrap_or_else(|| Coordenadas2D::new(0, 0)))),
+        false => Ok(click),
+    }
+}
+
+/// Verifica que los `argumentos` no incluyan ninguna de las [opciones][SOLO_GENERACION] que solo sirven para generar un tablero, ya que se ignorarían al leerlo de un archivo.
+///
+/// # Errores
+///
+/// Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] con la primera opción de generación indicada.
+pub(crate) fn verificar_sin_generacion(argumentos: &Argumentos) -> Result<(), ErrorIO> {
+    let indicada = SOLO_GENERACION
+        .iter()
+        .find(|o| argumentos.opcion(&[o]).is_some() || argumentos.bandera(&[o]));
+    match indicada {
+        Some(opcion) => Err(invalido(format!(
+            "{} solo puede usarse al generar un tablero, no con un archivo",
+            opcion
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Retorna una semilla tomada de la hora actual.
+fn semilla_por_defecto() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duracion| duracion.as_nanos() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsear(args: &[&str]) -> Argumentos {
+        let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+        Argumentos::parsear(&args, &OPCIONES_GENERACION, &BANDERAS).unwrap()
+    }
+
+    #[test]
+    fn densidad_con_primer_click_o_sin_adivinar() {
+        let argumentos = parsear(&["--densidad", "0.2", "--primer-click", "1,1"]);
+        assert!(generar(&argumentos).is_err());
+        let argumentos = parsear(&["--densidad", "0.2", "--sin-adivinar"]);
+        assert!(generar(&argumentos).is_err());
+        let argumentos = parsear(&["--densidad", "0.2", "--semilla", "7"]);
+        assert!(generar(&argumentos).is_ok());
+    }
+
+    #[test]
+    fn primer_click_por_defecto() {
+        let argumentos = parsear(&["--primer-click", "2,1"]);
+        let click = primer_click(&argumentos).unwrap();
+        assert_eq!(click, Some(Coordenadas2D::new(2, 1)));
+        let argumentos = parsear(&["--sin-adivinar"]);
+        let click = primer_click(&argumentos).unwrap();
+        assert_eq!(click, Some(Coordenadas2D::new(0, 0)));
+        assert_eq!(primer_click(&parsear(&["--minas", "3"])).unwrap(), None);
+    }
+
+    #[test]
+    fn opciones_de_generacion_con_un_archivo() {
+        for args in [
+            &["--minas", "3"][..],
+            &["--sin-adivinar"],
+            &["--semilla", "7"],
+        ] {
+            assert!(verificar_sin_generacion(&parsear(args)).is_err());
+        }
+        let argumentos = parsear(&["--topologia", "toroidal", "--vecindario", "caballo"]);
+        assert!(verificar_sin_generacion(&argumentos).is_ok());
+    }
+}
@@ -0,0 +1,153 @@
+//! # Jugar
+//! `jugar` es el subcomando que permite jugar una [`Partida`] desde la terminal, leyendo las jugadas de la entrada estándar.
+
+use crate::cli::argumentos::{parsear_coordenada, Argumentos};
+use crate::cli::generar;
+use crate::error::error_io::ErrorIO;
+use crate::error::Error;
+use crate::my_io::lector::Lector;
+use crate::tablero::builder::TableroBuilder;
+use crate::tablero::coordenada::Coordenadas2D;
+use crate::tablero::estado_partida::EstadoPartida;
+use crate::tablero::partida::Partida;
+use crate::tablero::Tablero;
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+
+/// Texto de ayuda del subcomando.
+pub(crate) const AYUDA: &str = "\
+Uso: buscaminas jugar [<archivo>] [opciones]
+
+Juega una partida sobre el tablero del archivo. Si no se indica un archivo, se genera
+un tablero aleatorio con las mismas opciones que el subcomando generar, que no pueden
+usarse junto con un archivo. Con --primer-click o --sin-adivinar, la partida comienza
+con esa celda revelada.
+
+Las jugadas se leen de la entrada estándar, una por línea:
+  r <x,y>                      Revela la celda.
+  m <x,y>                      Marca o desmarca la celda como posible mina.
+  q                            Abandona la partida.
+
+Opciones:
+  --simbolos <lista>           Símbolos del tablero, por ejemplo mina=X,espacio=0.
+  --relleno                    Completa las filas más cortas con paredes.
+  -h, --help                   Muestra esta ayuda.
+";
+/// Opciones que acepta el subcomando, además de las de [generación][generar::OPCIONES_GENERACION].
+pub(crate) const OPCIONES: [&str; 1] = ["--simbolos"];
+/// Banderas que acepta el subcomando.
+pub(crate) const BANDERAS: [&str; 2] = ["--relleno", "--sin-adivinar"];
+
+/// Juega una partida sobre el tablero indicado en los `argumentos`, leyendo las jugadas de la entrada estándar y mostrando el tablero en la salida estándar luego de cada una. Si el tablero se generó a partir de un primer click, la partida comienza con esa celda revelada.
+///
+/// # Errores
+///
+/// Retorna los errores producidos al construir el tablero o al leer y escribir la partida.
+pub(crate) fn ejecutar(argumentos: &Argumentos) -> Result<ExitCode, Error> {
+    let (tablero, primer_click) = crear_tablero(argumentos)?;
+    let mut partida = Partida::new(tablero);
+    if let Some(click) = primer_click {
+        partida.revelar(click)?;
+    }
+    jugar(&mut partida, io::stdin().lock(), &mut io::stdout().lock())?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Retorna el tablero indicado en los `argumentos`, leído del archivo o generado si no se indicó ninguno, junto con el [primer click][generar::primer_click] con el que se generó.
+///
+/// # Errores
+///
+/// - Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] si se indicó un archivo junto con opciones que solo sirven para generar un tablero.
+/// - Retorna los errores producidos al leer, construir o generar el tablero.
+fn crear_tablero(argumentos: &Argumentos) -> Result<(Tablero, Option<Coordenadas2D>), Error> {
+    if argumentos.posicionales().is_empty() {
+        return Ok((
+            generar::generar(argumentos)?,
+            generar::primer_click(argumentos)?,
+        ));
+    }
+    generar::verificar_sin_generacion(argumentos)?;
+    let casilleros = Lector::new(argumentos.archivo()?).leer_archivo()?;
+    let tablero = argumentos
+        .configurar(TableroBuilder::new(&casilleros)?)?
+        .crear_tablero()?;
+    Ok((tablero, None))
+}
+
+/// Juega la `partida` con las jugadas leídas de la `entrada`, escribiendo el tablero en la `salida` luego de cada una. Las jugadas inválidas se informan y no terminan la partida.
+///
+/// # Errores
+///
+/// Retorna los errores producidos al leer la entrada o al escribir la salida.
+pub(crate) fn jugar<R: BufRead, W: Write>(
+    partida: &mut Partida,
+    entrada: R,
+    salida: &mut W,
+) -> Result<(), ErrorIO> {
+    write!(salida, "{}", partida).map_err(ErrorIO::ErrorWrite)?;
+    for linea in entrada.lines() {
+        let linea = linea.map_err(ErrorIO::ErrorBufReader)?;
+        let mensaje = match jugada(partida, linea.trim()) {
+            Ok(false) => return Ok(()),
+            Ok(true) => format!("{}", partida),
+            Err(error) => format!("{:?}\n", error),
+        };
+        let mensaje = match partida.estado() {
+            EstadoPartida::EnCurso => mensaje,
+            EstadoPartida::Ganada => format!("{}¡Ganaste!\n", mensaje),
+            EstadoPartida::Perdida => format!("{}Perdiste.\n", mensaje),
+        };
+        write!(salida, "{}", mensaje).map_err(ErrorIO::ErrorWrite)?;
+        if partida.estado() != EstadoPartida::EnCurso {
+            return Ok(());
+        }
+    }
+    Ok(())
+}
+
+/// Realiza sobre la `partida` la jugada escrita en la `linea`. Retorna `false` si el jugador abandonó la partida.
+///
+/// # Errores
+///
+/// Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] si la jugada no es válida y los errores producidos al realizarla.
+fn jugada(partida: &mut Partida, linea: &str) -> Result<bool, Error> {
+    match linea.split_once(' ') {
+        Some(("r", coordenada)) => {
+            partida.revelar(parsear_coordenada(coordenada)?)?;
+        }
+        Some(("m", coordenada)) => {
+            partida.marcar(parsear_coordenada(coordenada)?)?;
+        }
+        None if linea == "q" => return Ok(false),
+        _ => {
+            let descripcion = format!("Jugada desconocida: {}", linea);
+            return Err(ErrorIO::ArgumentoInvalido(descripcion).into());
+        }
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jugar_hasta_ganar() {
+        let tablero = TableroBuilder::new("*..\n...\n")
+            .unwrap()
+            .crear_tablero()
+            .unwrap();
+        let mut partida = Partida::new(tablero);
+        let mut salida = vec![];
+        jugar(
+            &mut partida,
+            "m 0,0\nx\nr 2,1\nr 0,1\n".as_bytes(),
+            &mut salida,
+        )
+        .unwrap();
+        let salida = String::from_utf8(salida).unwrap();
+        assert!(salida.contains("Jugada desconocida: x"));
+        assert!(salida.ends_with("F1.\n11.\n¡Ganaste!\n"));
+        assert_eq!(partida.estado(), EstadoPartida::Ganada);
+    }
+}
@@ -0,0 +1,317 @@
+//! # Lote
+//! `lote` es un submódulo que contiene a [`Lote`], el conjunto de archivos de tableros que se resuelven en una misma ejecución.
+
+use crate::cli::resumen::Resumen;
+use crate::error::error_io::ErrorIO;
+use crate::error::Error;
+use crate::hilos_disponibles;
+use crate::my_io::escritor::Escritor;
+use crate::my_io::lector::Lector;
+use crate::my_io::RUTA_ESTANDAR;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::{fs, panic, thread};
+
+/// Extensión de los archivos de tableros que se buscan dentro de los directorios.
+const EXTENSION: &str = "txt";
+/// Sufijo que se agrega al nombre de cada archivo de entrada para obtener el de su solución.
+const SUFIJO_SOLUCION: &str = "solucion";
+
+#[derive(Debug, PartialEq, Eq)]
+/// Estructura que contiene los archivos de entrada de un lote, en orden, junto con el archivo de salida de cada uno.
+pub struct Lote {
+    /// Pares de archivo de entrada y archivo de salida.
+    trabajos: Vec<(PathBuf, PathBuf)>,
+}
+
+impl Lote {
+    /// Construye un [`Lote`] con los archivos de las `rutas` indicadas. De cada directorio se toman, recursivamente y en orden alfabético, los archivos `.txt` que no sean soluciones.
+    ///
+    /// La solución de `foo.txt` se escribe en `foo.solucion.txt`, junto al archivo de entrada o, si se indica un `directorio` de salida, dentro de él, conservando la estructura de los directorios recibidos.
+    ///
+    /// # Errores
+    ///
+    /// - Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] si alguna ruta es la [entrada estándar][RUTA_ESTANDAR].
+    /// - Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] si dos archivos de entrada tienen la misma salida, como `a/foo.txt` y `b/foo.txt` con un mismo `directorio`, un archivo indicado directamente y a través de su directorio, o un mismo archivo indicado con rutas distintas como `a/foo.txt` y `./a/foo.txt`.
+    /// - Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] si la salida de un archivo es otro de los archivos de entrada, como `foo.txt` y `foo.solucion.txt`.
+    /// - Retorna [`ErrorFile`][ErrorIO::ErrorFile] si no puede recorrerse algún directorio.
+    pub fn buscar(rutas: &[String], directorio: Option<&Path>) -> Result<Lote, ErrorIO> {
+        let mut trabajos = vec![];
+        for ruta in rutas {
+            if ruta == RUTA_ESTANDAR {
+                let descripcion = String::from("La entrada estándar no puede resolverse en lote");
+                return Err(ErrorIO::ArgumentoInvalido(descripcion));
+            }
+            let ruta = Path::new(ruta);
+            let base = match ruta.is_dir() {
+                true => ruta,
+                false => ruta.parent().unwrap_or(ruta),
+            };
+            for entrada in Self::expandir(ruta)? {
+                let salida = Self::ruta_salida(&entrada, base, directorio);
+                trabajos.push((entrada, salida));
+            }
+        }
+        Self::verificar_salidas(&trabajos)?;
+        Ok(Lote { trabajos })
+    }
+
+    /// Verifica que no haya dos trabajos con el mismo archivo de salida, ya que la solución de uno sobrescribiría la del otro, ni un archivo de salida que sea a la vez una de las entradas, ya que se sobrescribiría mientras otro hilo lo lee. Las rutas se comparan en su forma [canónica][Lote::canonica].
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] con el primer archivo de salida repetido o que también es una entrada.
+    fn verificar_salidas(trabajos: &[(PathBuf, PathBuf)]) -> Result<(), ErrorIO> {
+        let entradas: HashSet<PathBuf> = trabajos.iter().map(|(e, _)| Self::canonica(e)).collect();
+        let mut salidas = HashSet::new();
+        for (entrada, salida) in trabajos {
+            let canonica = Self::canonica(salida);
+            let conflicto = match entradas.contains(&canonica) {
+                true => "que es uno de los archivos de entrada",
+                false if !salidas.insert(canonica) => "que ya es la salida de otro archivo",
+                false => continue,
+            };
+            return Err(ErrorIO::ArgumentoInvalido(format!(
+                "La solución de {} se escribiría en {}, {}",
+                entrada.display(),
+                salida.display(),
+                conflicto
+            )));
+        }
+        Ok(())
+    }
+
+    /// Retorna la forma canónica de la `ruta`, sin componentes `.` ni `..` ni enlaces simbólicos, para que dos rutas a un mismo archivo sean iguales. Si el archivo todavía no existe, se canoniza su directorio y se le agrega el nombre del archivo; si tampoco existe el directorio, solo se quitan los componentes `.`.
+    fn canonica(ruta: &Path) -> PathBuf {
+        if let Ok(canonica) = fs::canonicalize(ruta) {
+            return canonica;
+        }
+        let directorio = match ruta.parent() {
+            Some(directorio) if !directorio.as_os_str().is_empty() => directorio,
+            _ => Path::new("."),
+        };
+        match (fs::canonicalize(directorio), ruta.file_name()) {
+            (Ok(directorio), Some(nombre)) => directorio.join(nombre),
+            _ => ruta.components().collect(),
+        }
+    }
+
+    /// Retorna los pares de archivo de entrada y archivo de salida del lote.
+    pub fn trabajos(&self) -> &[(PathBuf, PathBuf)] {
+        &self.trabajos
+    }
+
+    /// Resuelve cada archivo del lote con la función `solucionar`, que recibe el contenido del archivo y retorna el texto de la solución, y la escribe en su archivo de salida. Un archivo que falla no interrumpe la resolución de los demás.
+    ///
+    /// Los archivos se reparten entre hilos, uno por cada núcleo disponible, que toman el siguiente archivo pendiente al terminar con el anterior. El [`Resumen`] conserva el orden del lote sin importar cuál termina primero.
+    pub fn resolver<F>(&self, solucionar: F) -> Resumen
+    where
+        F: Fn(&str) -> Result<String, Error> + Sync,
+    {
+        let mut resultados = self.resolver_en_paralelo(&solucionar);
+        resultados.sort_by_key(|(indice, _)| *indice);
+        let mut resumen = Resumen::default();
+        for (indice, resultado) in resultados {
+            resumen.registrar(self.trabajos[indice].0.clone(), resultado);
+        }
+        resumen
+    }
+
+    /// Resuelve los archivos del lote repartidos entre los hilos y retorna el resultado de cada uno junto con su índice en el lote, en el orden en que terminaron.
+    fn resolver_en_paralelo<F>(&self, solucionar: &F) -> Vec<(usize, Result<(), Error>)>
+    where
+        F: Fn(&str) -> Result<String, Error> + Sync,
+    {
+        let siguiente = AtomicUsize::new(0);
+        let trabajar = || {
+            let mut resultados = vec![];
+            loop {
+                let indice = siguiente.fetch_add(1, Ordering::Relaxed);
+                let Some((entrada, salida)) = self.trabajos.get(indice) else {
+                    return resultados;
+                };
+                resultados.push((indice, Self::resolver_archivo(entrada, salida, solucionar)));
+            }
+        };
+        thread::scope(|s| {
+            let hilos: Vec<_> = (0..hilos_disponibles().min(self.trabajos.len()))
+                .map(|_| s.spawn(trabajar))
+                .collect();
+            hilos
+                .into_iter()
+                .flat_map(|hilo| hilo.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+                .collect()
+        })
+    }
+
+    /// Lee el archivo de `entrada`, lo resuelve con `solucionar` y escribe la solución en el archivo de `salida`, creando sus directorios si no existen.
+    fn resolver_archivo<F: Fn(&str) -> Result<String, Error>>(
+        entrada: &Path,
+        salida: &Path,
+        solucionar: &F,
+    ) -> Result<(), Error> {
+        let casilleros = Lector::new(&entrada.to_string_lossy()).leer_archivo()?;
+        let solucion = solucionar(&casilleros)?;
+        if let Some(directorio) = salida.parent() {
+            fs::create_dir_all(directorio).map_err(ErrorIO::ErrorFile)?;
+        }
+        Escritor::new(&salida.to_string_lossy()).imprimir_item(&solucion)?;
+        Ok(())
+    }
+
+    /// Retorna la `ruta` si es un archivo o, si es un directorio, los archivos de tableros que contiene, recursivamente y en orden alfabético.
+    fn expandir(ruta: &Path) -> Result<Vec<PathBuf>, ErrorIO> {
+        if !ruta.is_dir() {
+            return Ok(vec![ruta.to_path_buf()]);
+        }
+        let mut entradas: Vec<PathBuf> = fs::read_dir(ruta)
+            .and_then(|entradas| entradas.map(|e| e.map(|e| e.path())).collect())
+            .map_err(ErrorIO::ErrorFile)?;
+        entradas.sort();
+        let mut archivos = vec![];
+        for entrada in entradas {
+            match entrada.is_dir() {
+                true => archivos.extend(Self::expandir(&entrada)?),
+                false if Self::es_tablero(&entrada) => archivos.push(entrada),
+                false => {}
+            }
+        }
+        Ok(archivos)
+    }
+
+    /// Retorna `true` si el archivo de la `ruta` tiene la [extensión][EXTENSION] de un tablero y no es una solución.
+    fn es_tablero(ruta: &Path) -> bool {
+        let solucion = format!(".{}.{}", SUFIJO_SOLUCION, EXTENSION);
+        ruta.extension().is_some_and(|e| e == EXTENSION)
+            && !ruta.to_string_lossy().ends_with(&solucion)
+    }
+
+    /// Retorna la ruta de la solución del archivo de `entrada`, encontrado a partir de la ruta `base`, dentro del `directorio` de salida o junto a la entrada si no se indica.
+    fn ruta_salida(entrada: &Path, base: &Path, directorio: Option<&Path>) -> PathBuf {
+        let nombre = match (entrada.file_stem(), entrada.extension()) {
+            (Some(nombre), Some(extension)) => format!(
+                "{}.{}.{}",
+                nombre.to_string_lossy(),
+                SUFIJO_SOLUCION,
+                extension.to_string_lossy()
+            ),
+            (nombre, _) => format!(
+                "{}.{}",
+                nombre.unwrap_or_default().to_string_lossy(),
+                SUFIJO_SOLUCION
+            ),
+        };
+        match directorio {
+            Some(directorio) => directorio
+                .join(entrada.strip_prefix(base).unwrap_or(entrada))
+                .with_file_name(nombre),
+            None => entrada.with_file_name(nombre),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nombre_de_la_solucion() {
+        let salida = Lote::ruta_salida(Path::new("a/b/foo.txt"), Path::new("a"), None);
+        assert_eq!(salida, PathBuf::from("a/b/foo.solucion.txt"));
+        let directorio = Some(Path::new("out"));
+        let salida = Lote::ruta_salida(Path::new("a/b/foo.txt"), Path::new("a"), directorio);
+        assert_eq!(salida, PathBuf::from("out/b/foo.solucion.txt"));
+        let salida = Lote::ruta_salida(Path::new("a/foo"), Path::new("a"), None);
+        assert_eq!(salida, PathBuf::from("a/foo.solucion"));
+    }
+
+    #[test]
+    fn solo_se_buscan_tableros() {
+        assert!(Lote::es_tablero(Path::new("a/foo.txt")));
+        assert!(!Lote::es_tablero(Path::new("a/foo.solucion.txt")));
+        assert!(!Lote::es_tablero(Path::new("a/foo.md")));
+    }
+
+    #[test]
+    fn buscar_en_un_directorio() {
+        let lote = Lote::buscar(&[String::from("test_files")], None).unwrap();
+        let entradas: Vec<&PathBuf> = lote.trabajos().iter().map(|(e, _)| e).collect();
+        assert!(entradas.contains(&&PathBuf::from("test_files/test1.txt")));
+        assert!(entradas.windows(2).all(|par| par[0] < par[1]));
+    }
+
+    #[test]
+    fn resolver_conserva_el_orden_del_lote() {
+        let directorio = std::env::temp_dir().join("buscaminas_lote_orden");
+        let rutas = [
+            "test_files/test2.txt",
+            "test_files/no_existe.txt",
+            "test_files/test1.txt",
+        ];
+        let rutas: Vec<String> = rutas.iter().map(|r| r.to_string()).collect();
+        let lote = Lote::buscar(&rutas, Some(&directorio)).unwrap();
+        let resumen = lote.resolver(|casilleros| Ok(casilleros.len().to_string()));
+        let resultado = fs::read_to_string(directorio.join("test1.solucion.txt"));
+        fs::remove_dir_all(&directorio).unwrap();
+        assert_eq!(
+            resumen.resueltos(),
+            [PathBuf::from(&rutas[0]), PathBuf::from(&rutas[2])]
+        );
+        assert_eq!(resumen.fallidos()[0].0, PathBuf::from(&rutas[1]));
+        assert_eq!(resultado.unwrap(), "14");
+    }
+
+    #[test]
+    fn salidas_repetidas() {
+        let rutas = [
+            String::from("test_files"),
+            String::from("test_files/test1.txt"),
+        ];
+        assert!(Lote::buscar(&rutas, None).is_err());
+        let directorio = std::env::temp_dir().join("buscaminas_lote_repetidas");
+        let rutas = [
+            String::from("test_files/test1.txt"),
+            String::from("src/../test_files/test1.txt"),
+        ];
+        assert!(Lote::buscar(&rutas, Some(&directorio)).is_err());
+        assert!(Lote::buscar(&rutas[..1], Some(&directorio)).is_ok());
+    }
+
+    #[test]
+    fn salidas_repetidas_con_rutas_equivalentes() {
+        for alias in [
+            "./test_files/test1.txt",
+            "test_files/./test1.txt",
+            "test_files/../test_files/test1.txt",
+        ] {
+            let rutas = [String::from("test_files/test1.txt"), String::from(alias)];
+            assert!(Lote::buscar(&rutas, None).is_err());
+        }
+    }
+
+    #[test]
+    fn salida_que_es_una_entrada() {
+        let directorio = std::env::temp_dir().join("buscaminas_lote_entrada");
+        fs::create_dir_all(&directorio).unwrap();
+        fs::write(directorio.join("a.txt"), "*.\n").unwrap();
+        fs::write(directorio.join("a.solucion.txt"), "*.\n").unwrap();
+        let entrada = directorio.join("a.txt").to_string_lossy().to_string();
+        let solucion = directorio.join("a.solucion.txt");
+        let rutas = [entrada.clone(), solucion.to_string_lossy().to_string()];
+        let resultado = Lote::buscar(&rutas, None);
+        let alias = directorio
+            .join("..")
+            .join("buscaminas_lote_entrada/a.solucion.txt");
+        let rutas = [entrada, alias.to_string_lossy().to_string()];
+        let resultado_con_alias = Lote::buscar(&rutas, None);
+        fs::remove_dir_all(&directorio).unwrap();
+        assert!(resultado.is_err());
+        assert!(resultado_con_alias.is_err());
+    }
+
+    #[test]
+    fn la_entrada_estandar_no_puede_resolverse_en_lote() {
+        assert!(Lote::buscar(&[String::from(RUTA_ESTANDAR)], None).is_err());
+    }
+}
@@ -0,0 +1,72 @@
+//! # CLI
+//! `cli` es un módulo que contiene la interfaz de línea de comandos del binario: el reconocimiento de los argumentos y la ejecución de cada [subcomando][comando::Comando].
+//!
+//! Los argumentos se reconocen únicamente con la biblioteca estándar. Cada subcomando acepta `--help` para mostrar su uso y sus opciones.
+
+pub mod argumentos;
+pub mod comando;
+mod convertir;
+mod estadisticas;
+mod generar;
+mod jugar;
+pub mod lote;
+mod pista;
+mod resolver;
+pub mod resumen;
+mod validar;
+
+use crate::cli::argumentos::AYUDA;
+use crate::cli::comando::{Comando, COMANDOS};
+use crate::error::error_io::ErrorIO;
+use crate::error::Error;
+use crate::my_io::escritor::Escritor;
+use crate::my_io::RUTA_ESTANDAR;
+use std::process::ExitCode;
+
+/// Ejecuta la línea de comandos con los `args` recibidos, sin incluir el nombre del programa.
+///
+/// El primer argumento indica el [subcomando][Comando]. Si no corresponde a ninguno, se ejecuta [`resolver`][Comando::Resolver] con todos los argumentos, por lo que `buscaminas <archivo>` sigue resolviendo el tablero del archivo.
+///
+/// # Errores
+///
+/// - Retorna [`ErrorCLI`][ErrorIO::ErrorCLI] si no se recibió ningún argumento.
+/// - Retorna los errores producidos al ejecutar el subcomando.
+pub fn ejecutar(args: &[String]) -> Result<ExitCode, Error> {
+    let primero = args.first().ok_or(ErrorIO::ErrorCLI)?;
+    if AYUDA.contains(&primero.as_str()) {
+        Escritor::new(RUTA_ESTANDAR).imprimir_item(&ayuda())?;
+        return Ok(ExitCode::SUCCESS);
+    }
+    match Comando::identificar(primero) {
+        Some(comando) => comando.ejecutar(&args[1..]),
+        None => Comando::Resolver.ejecutar(args),
+    }
+}
+
+/// Retorna el texto de ayuda general, con la lista de subcomandos.
+fn ayuda() -> String {
+    let comandos: String = COMANDOS
+        .iter()
+        .map(|c| format!("  {:<15}{}\n", c.nombre(), c.descripcion()))
+        .collect();
+    format!(
+        "Uso: buscaminas <comando> [opciones]\n\nComandos:\n{}\nEjecutar buscaminas <comando> --help para ver las opciones de cada comando.\n",
+        comandos
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ayuda_lista_todos_los_comandos() {
+        let ayuda = ayuda();
+        assert!(COMANDOS.iter().all(|c| ayuda.contains(c.nombre())));
+    }
+
+    #[test]
+    fn ejecutar_sin_argumentos() {
+        assert!(ejecutar(&[]).is_err());
+    }
+}
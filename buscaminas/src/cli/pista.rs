@@ -0,0 +1,121 @@
+//! # Pista
+//! `pista` es el subcomando que indica qué celdas de un tablero parcial pueden descubrirse con certeza y, si no hay ninguna, cuál es la más segura.
+
+use crate::cli::argumentos::Argumentos;
+use crate::error::Error;
+use crate::my_io::escritor::Escritor;
+use crate::my_io::lector::Lector;
+use crate::tablero::builder::TableroBuilder;
+use crate::tablero::coordenada::Coordenadas2D;
+use crate::tablero::tablero_parcial::TableroParcial;
+use std::process::ExitCode;
+
+/// Texto de ayuda del subcomando.
+pub(crate) const AYUDA: &str = "\
+Uso: buscaminas pista <archivo> [opciones]
+
+Indica las celdas desconocidas del tablero parcial del archivo que con certeza son minas
+o son seguras. Las celdas desconocidas se marcan con ?. Si no puede deducirse ninguna y
+se indica la cantidad total de minas, sugiere la celda con menor probabilidad de mina.
+
+Opciones:
+  --minas <n>                  Cantidad total de minas del tablero.
+  -o, --output <archivo>       Archivo de salida. Por defecto, la salida estándar.
+  --topologia <nombre>         acotada (por defecto) o toroidal.
+  --vecindario <nombre>        moore (por defecto), von-neumann, caballo o hexagonal.
+  --simbolos <lista>           Símbolos del tablero, por ejemplo mina=X,oculta=_.
+  --relleno                    Completa las filas más cortas con paredes.
+  -h, --help                   Muestra esta ayuda.
+";
+/// Nombre de la opción que indica la cantidad total de minas.
+const MINAS: [&str; 1] = ["--minas"];
+/// Opciones que acepta el subcomando.
+pub(crate) const OPCIONES: [&str; 6] = [
+    MINAS[0],
+    "-o",
+    "--output",
+    "--topologia",
+    "--vecindario",
+    "--simbolos",
+];
+/// Banderas que acepta el subcomando.
+pub(crate) const BANDERAS: [&str; 1] = ["--relleno"];
+
+/// Escribe la pista correspondiente al tablero parcial indicado en los `argumentos`.
+///
+/// # Errores
+///
+/// Retorna los errores producidos al leer el archivo, al construir el tablero, al razonar sobre él o al escribir la pista.
+pub(crate) fn ejecutar(argumentos: &Argumentos) -> Result<ExitCode, Error> {
+    let casilleros = Lector::new(argumentos.archivo()?).leer_archivo()?;
+    let parcial = argumentos
+        .configurar(TableroBuilder::new(&casilleros)?)?
+        .crear_tablero_parcial()?;
+    let minas = argumentos
+        .opcion(&MINAS)
+        .map(|_| argumentos.numero(&MINAS, 0))
+        .transpose()?;
+    let pista = pista(&parcial, minas)?;
+    Escritor::new(argumentos.salida()).imprimir_item(&pista)?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Retorna el texto de la pista para el tablero `parcial`. Si no puede deducirse ninguna celda y se conoce la cantidad total de `minas`, sugiere la celda más segura.
+///
+/// # Errores
+///
+/// Retorna los errores producidos al deducir o al calcular las probabilidades.
+fn pista(parcial: &TableroParcial, minas: Option<usize>) -> Result<String, Error> {
+    let deduccion = parcial.deducir()?;
+    if !deduccion.minas().is_empty() || !deduccion.seguras().is_empty() {
+        return Ok(format!(
+            "Minas:{}\nSeguras:{}\n",
+            listar(deduccion.minas()),
+            listar(deduccion.seguras())
+        ));
+    }
+    let probabilidades = match minas {
+        Some(minas) => parcial.calcular_probabilidades(minas)?,
+        None => return Ok(String::from("No puede deducirse ninguna celda.\n")),
+    };
+    Ok(match probabilidades.mas_segura() {
+        Some(celda) => format!(
+            "No puede deducirse ninguna celda. La más segura es{} (probabilidad de mina: {:.2}%)\n",
+            listar(&[celda]),
+            probabilidades.probabilidad(&celda).unwrap_or(0.0) * 100.0
+        ),
+        None => String::from("No quedan celdas desconocidas.\n"),
+    })
+}
+
+/// Retorna las `coordenadas` escritas como ` (x, y)`, una a continuación de la otra.
+fn listar(coordenadas: &[Coordenadas2D]) -> String {
+    coordenadas
+        .iter()
+        .map(|c| format!(" ({}, {})", c.x(), c.y()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tablero::tablero_parcial::tests::crear_parcial;
+
+    #[test]
+    fn pista_con_celdas_deducibles() {
+        let parcial = crear_parcial("?1.\n");
+        assert_eq!(pista(&parcial, None).unwrap(), "Minas: (0, 0)\nSeguras:\n");
+    }
+
+    #[test]
+    fn pista_sin_celdas_deducibles() {
+        let parcial = crear_parcial("??\n??\n");
+        let resultado = pista(&parcial, None).unwrap();
+        assert_eq!(resultado, "No puede deducirse ninguna celda.\n");
+        let resultado = pista(&parcial, Some(1)).unwrap();
+        assert_eq!(
+            resultado,
+            "No puede deducirse ninguna celda. La más segura es (0, 0) (probabilidad de mina: 25.00%)\n"
+        );
+    }
+}
@@ -0,0 +1,164 @@
+//! # Resolver
+//! `resolver` es el subcomando que resuelve un tablero, de dos o tres dimensiones, y escribe su solución.
+
+use crate::cli::argumentos::{parsear_simbolos, Argumentos, RELLENO, SALIDA, SIMBOLOS};
+use crate::cli::lote::Lote;
+use crate::error::error_io::ErrorIO;
+use crate::error::Error;
+use crate::my_io::escritor::Escritor;
+use crate::my_io::lector::Lector;
+use crate::my_io::RUTA_ESTANDAR;
+use crate::tablero::builder::TableroBuilder;
+use crate::tablero::flujo::Flujo;
+use crate::tablero::simbolos::Simbolos;
+use crate::tablero::topologia::Topologia;
+use crate::tablero::Tablero;
+use std::path::Path;
+use std::process::ExitCode;
+
+/// Texto de ayuda del subcomando.
+pub(crate) const AYUDA: &str = "\
+Uso: buscaminas resolver <archivo>... [opciones]
+
+Resuelve el tablero del archivo, de dos o tres dimensiones, y escribe la solución.
+Si el archivo es \"-\" el tablero se lee de la entrada estándar.
+
+Si se indican varios archivos o algún directorio, se resuelven todos los tableros en
+lote: de cada directorio se toman los archivos .txt, y la solución de foo.txt se
+escribe en foo.solucion.txt. Al terminar se muestra un resumen de los resultados.
+
+Con --flujo el tablero se resuelve fila por fila a medida que se lee, sin cargarlo
+completo en memoria. Solo admite tableros de dos dimensiones con topología acotada.
+Todas las celdas se alinean al ancho del mayor recuento que admite el vecindario.
+
+Opciones:
+  -o, --output <archivo>       Archivo de salida. Por defecto, la salida estándar.
+  -d, --directorio <dir>       Directorio de salida de las soluciones del lote.
+  --topologia <nombre>         acotada (por defecto) o toroidal.
+  --vecindario <nombre>        moore (por defecto), von-neumann, caballo o hexagonal.
+  --simbolos <lista>           Símbolos del tablero, por ejemplo mina=X,espacio=0.
+  --simbolos-salida <lista>    Símbolos con los que se escribe la solución.
+  --relleno                    Completa las filas más cortas con paredes.
+  --flujo                      Resuelve el tablero fila por fila a medida que se lee.
+  -h, --help                   Muestra esta ayuda.
+";
+/// Nombre de la opción que indica los símbolos con los que se escribe la solución.
+const SIMBOLOS_SALIDA: [&str; 1] = ["--simbolos-salida"];
+/// Nombres de la opción que indica el directorio de salida de un lote.
+const DIRECTORIO: [&str; 2] = ["-d", "--directorio"];
+/// Opciones que acepta el subcomando.
+pub(crate) const OPCIONES: [&str; 8] = [
+    SALIDA[0],
+    SALIDA[1],
+    DIRECTORIO[0],
+    DIRECTORIO[1],
+    "--topologia",
+    "--vecindario",
+    "--simbolos",
+    SIMBOLOS_SALIDA[0],
+];
+/// Nombre de la bandera que indica que el tablero se resuelve en [flujo][Flujo].
+const FLUJO: [&str; 1] = ["--flujo"];
+/// Banderas que acepta el subcomando.
+pub(crate) const BANDERAS: [&str; 2] = [RELLENO[0], FLUJO[0]];
+
+/// Resuelve el tablero del archivo indicado en los `argumentos` y escribe la solución en la salida indicada. Si se indican varios archivos, algún directorio o un directorio de salida, resuelve el [`Lote`] correspondiente.
+///
+/// # Errores
+///
+/// Retorna los errores producidos al leer el archivo, al construir el tablero o al escribir la solución.
+pub(crate) fn ejecutar(argumentos: &Argumentos) -> Result<ExitCode, Error> {
+    let simbolos = argumentos
+        .opcion(&SIMBOLOS_SALIDA)
+        .map(parsear_simbolos)
+        .transpose()?;
+    if argumentos.bandera(&FLUJO) {
+        return resolver_en_flujo(argumentos, simbolos);
+    }
+    let directorio = argumentos.opcion(&DIRECTORIO).map(Path::new);
+    match argumentos.posicionales() {
+        [archivo] if directorio.is_none() && !Path::new(archivo).is_dir() => {
+            let casilleros = Lector::new(archivo).leer_archivo()?;
+            let solucion = solucionar(argumentos, &casilleros, simbolos)?;
+            Escritor::new(argumentos.salida()).imprimir_item(&solucion)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        [] => {
+            Err(ErrorIO::ArgumentoInvalido(String::from("Se esperaba al menos un archivo")).into())
+        }
+        rutas => resolver_lote(argumentos, Lote::buscar(rutas, directorio)?, simbolos),
+    }
+}
+
+/// Resuelve cada archivo del `lote` y escribe el resumen en la salida estándar. Retorna [`ExitCode::FAILURE`] si algún archivo no pudo resolverse.
+///
+/// # Errores
+///
+/// Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] si se indicó un archivo de [salida][SALIDA], y los errores producidos al escribir el resumen.
+fn resolver_lote(
+    argumentos: &Argumentos,
+    lote: Lote,
+    simbolos: Option<Simbolos>,
+) -> Result<ExitCode, Error> {
+    if argumentos.opcion(&SALIDA).is_some() {
+        let descripcion = String::from("Para resolver en lote se indica un --directorio de salida");
+        return Err(ErrorIO::ArgumentoInvalido(descripcion).into());
+    }
+    let resumen = lote.resolver(|casilleros| solucionar(argumentos, casilleros, simbolos.clone()));
+    Escritor::new(RUTA_ESTANDAR).imprimir_item(&resumen)?;
+    Ok(match resumen.es_exitoso() {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    })
+}
+
+/// Resuelve en [flujo][Flujo] el tablero del archivo indicado en los `argumentos`, escribiendo cada fila resuelta en la salida indicada a medida que se lee, con los `simbolos` de salida indicados, si los hay.
+///
+/// # Errores
+///
+/// - Retorna [`ArgumentoInvalido`][ErrorIO::ArgumentoInvalido] si se indicó una topología distinta de la acotada o el relleno de las filas.
+/// - Retorna los errores producidos al abrir los archivos o al resolver el tablero.
+fn resolver_en_flujo(
+    argumentos: &Argumentos,
+    simbolos: Option<Simbolos>,
+) -> Result<ExitCode, Error> {
+    if argumentos.topologia()? != Topologia::Acotada || argumentos.bandera(&RELLENO) {
+        let descripcion = String::from("En flujo solo se admiten tableros acotados y sin relleno");
+        return Err(ErrorIO::ArgumentoInvalido(descripcion).into());
+    }
+    let mut flujo = Flujo::default().con_vecindario(argumentos.vecindario()?);
+    if let Some(entrada) = argumentos.opcion(&SIMBOLOS) {
+        flujo = flujo.con_simbolos(parsear_simbolos(entrada)?)?;
+    }
+    if let Some(simbolos) = simbolos {
+        flujo = flujo.con_simbolos_salida(simbolos)?;
+    }
+    let entrada = Lector::new(argumentos.archivo()?).abrir()?;
+    flujo.resolver(entrada, &mut Escritor::new(argumentos.salida()).abrir()?)?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Resuelve el tablero de los `casilleros`, de dos o tres dimensiones, con la configuración de los `argumentos`, y retorna la solución escrita con los `simbolos` indicados.
+///
+/// # Errores
+///
+/// Retorna los errores producidos al configurar o al construir el tablero.
+fn solucionar(
+    argumentos: &Argumentos,
+    casilleros: &str,
+    simbolos: Option<Simbolos>,
+) -> Result<String, Error> {
+    let builder = argumentos.configurar(TableroBuilder::new(casilleros)?)?;
+    Ok(match builder.es_tridimensional() {
+        true => mostrar(builder.crear_tablero_3d()?, simbolos).to_string(),
+        false => mostrar(builder.crear_tablero()?, simbolos).to_string(),
+    })
+}
+
+/// Retorna el `tablero` configurado para mostrarse con los `simbolos` indicados, si los hay.
+fn mostrar<const N: usize>(tablero: Tablero<N>, simbolos: Option<Simbolos>) -> Tablero<N> {
+    match simbolos {
+        Some(simbolos) => tablero.con_simbolos(simbolos),
+        None => tablero,
+    }
+}
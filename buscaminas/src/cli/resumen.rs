@@ -0,0 +1,50 @@
+//! # Resumen
+//! `resumen` es un submódulo que contiene a [`Resumen`], el resultado de resolver un [`Lote`](super::lote::Lote) de archivos.
+
+use crate::error::Error;
+use std::fmt;
+use std::path::PathBuf;
+
+#[derive(Debug, Default)]
+/// Estructura que contiene los archivos que se resolvieron correctamente y los que fallaron, junto con su error.
+pub struct Resumen {
+    /// Archivos de entrada resueltos, en el orden del lote.
+    resueltos: Vec<PathBuf>,
+    /// Archivos de entrada que no pudieron resolverse, en el orden del lote, junto con el error producido.
+    fallidos: Vec<(PathBuf, Error)>,
+}
+
+impl fmt::Display for Resumen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Resueltos: {}", self.resueltos.len())?;
+        writeln!(f, "Fallidos: {}", self.fallidos.len())?;
+        self.fallidos
+            .iter()
+            .try_for_each(|(path, error)| writeln!(f, "  {}: {:?}", path.display(), error))
+    }
+}
+
+impl Resumen {
+    /// Registra el `resultado` de resolver el archivo de `entrada`.
+    pub(crate) fn registrar(&mut self, entrada: PathBuf, resultado: Result<(), Error>) {
+        match resultado {
+            Ok(()) => self.resueltos.push(entrada),
+            Err(error) => self.fallidos.push((entrada, error)),
+        }
+    }
+
+    /// Retorna los archivos de entrada que se resolvieron correctamente.
+    pub fn resueltos(&self) -> &[PathBuf] {
+        &self.resueltos
+    }
+
+    /// Retorna los archivos de entrada que no pudieron resolverse, junto con el error producido.
+    pub fn fallidos(&self) -> &[(PathBuf, Error)] {
+        &self.fallidos
+    }
+
+    /// Retorna `true` si todos los archivos se resolvieron correctamente.
+    pub fn es_exitoso(&self) -> bool {
+        self.fallidos.is_empty()
+    }
+}
@@ -0,0 +1,67 @@
+//! # Validar
+//! `validar` es el subcomando que verifica si un problema parcial tiene solución única.
+
+use crate::cli::argumentos::{invalido, parsear_numero, Argumentos};
+use crate::error::Error;
+use crate::my_io::escritor::Escritor;
+use crate::my_io::lector::Lector;
+use crate::tablero::builder::TableroBuilder;
+use crate::tablero::unicidad::Unicidad;
+use std::process::ExitCode;
+
+/// Texto de ayuda del subcomando.
+pub(crate) const AYUDA: &str = "\
+Uso: buscaminas validar <archivo> <minas> [opciones]
+
+Verifica si el tablero parcial del archivo, con la cantidad total de minas indicada,
+tiene solución única. Las celdas desconocidas se marcan con ?. Si el problema no tiene
+solución única, finaliza con código de salida 1. Si admite demasiadas distribuciones de
+minas para decidirlo, finaliza con código de salida 2.
+
+Opciones:
+  -o, --output <archivo>       Archivo de salida. Por defecto, la salida estándar.
+  --topologia <nombre>         acotada (por defecto) o toroidal.
+  --vecindario <nombre>        moore (por defecto), von-neumann, caballo o hexagonal.
+  --simbolos <lista>           Símbolos del tablero, por ejemplo mina=X,oculta=_.
+  --relleno                    Completa las filas más cortas con paredes.
+  -h, --help                   Muestra esta ayuda.
+";
+/// Opciones que acepta el subcomando.
+pub(crate) const OPCIONES: [&str; 5] = [
+    "-o",
+    "--output",
+    "--topologia",
+    "--vecindario",
+    "--simbolos",
+];
+/// Banderas que acepta el subcomando.
+pub(crate) const BANDERAS: [&str; 1] = ["--relleno"];
+/// Código de salida que indica que no pudo decidirse si el problema tiene solución única.
+pub(crate) const CODIGO_INDETERMINADA: u8 = 2;
+
+/// Verifica si el tablero parcial y la cantidad de minas indicados en los `argumentos` tienen solución única y escribe el resultado. Retorna [`ExitCode::FAILURE`] si no la tienen y [`CODIGO_INDETERMINADA`] si no pudo decidirse.
+///
+/// # Errores
+///
+/// - Retorna [`ArgumentoInvalido`][crate::error::error_io::ErrorIO::ArgumentoInvalido] si no se indicaron el archivo y la cantidad de minas.
+/// - Retorna los errores producidos al leer el archivo, al construir el tablero o al escribir el resultado.
+pub(crate) fn ejecutar(argumentos: &Argumentos) -> Result<ExitCode, Error> {
+    let (path, minas) = match argumentos.posicionales() {
+        [path, minas] => (path, parsear_numero(minas)?),
+        _ => {
+            let descripcion = String::from("Se esperaba un archivo y una cantidad de minas");
+            return Err(invalido(descripcion).into());
+        }
+    };
+    let casilleros = Lector::new(path).leer_archivo()?;
+    let parcial = argumentos
+        .configurar(TableroBuilder::new(&casilleros)?)?
+        .crear_tablero_parcial()?;
+    let unicidad = parcial.verificar_unicidad(minas)?;
+    Escritor::new(argumentos.salida()).imprimir_item(&unicidad)?;
+    match unicidad {
+        Unicidad::Unica(_) => Ok(ExitCode::SUCCESS),
+        Unicidad::Indeterminada => Ok(ExitCode::from(CODIGO_INDETERMINADA)),
+        _ => Ok(ExitCode::FAILURE),
+    }
+}
@@ -14,6 +14,8 @@ pub enum ErrorIO {
     ErrorFile(io::Error),
     /// Variante para representar que el error fue lanzado por no cumplir con los parámetros necesarios desde la línea de comandos
     ErrorCLI,
+    /// Variante para representar que un argumento de la línea de comandos no es válido. Contiene la descripción del problema.
+    ArgumentoInvalido(String),
 }
 
 impl fmt::Debug for ErrorIO {
@@ -25,8 +27,9 @@ impl fmt::Debug for ErrorIO {
             ErrorIO::ErrorFile(error) => write!(f, "{}", error),
             ErrorIO::ErrorCLI => write!(
                 f,
-                "No se ha pasado la ruta del archivo con el problema a resolver."
+                "Uso: buscaminas <comando> [opciones]. Ejecutar buscaminas --help para ver los comandos disponibles."
             ),
+            ErrorIO::ArgumentoInvalido(descripcion) => write!(f, "{}", descripcion),
         }
     }
 }
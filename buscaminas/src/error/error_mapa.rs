@@ -14,6 +14,18 @@ pub enum ErrorMapa {
     MapaVacio,
     /// Variante que indica que se intento acceder a una celda del [Tablero](../tablero/mod.rs/struct.Tablero.html) que no existe.
     CeldaInexistente,
+    /// Variante que indica que se intentó generar un tablero con más minas que celdas disponibles.
+    DemasiadasMinas(usize),
+    /// Variante que indica que la densidad de minas solicitada no pertenece al intervalo `[0, 1]`.
+    DensidadInvalida,
+    /// Variante que indica que no se encontró un tablero que pueda resolverse sin adivinar dentro de la cantidad máxima de intentos.
+    GeneracionFallida,
+    /// Variante que indica que los números de un tablero parcial se contradicen entre sí, por lo que no existe ninguna distribución de minas que los cumpla.
+    PistasInconsistentes,
+    /// Variante que indica que un conjunto de [Simbolos](../tablero/simbolos/struct.Simbolos.html) contiene el caracter indicado más de una vez o lo usa aunque esté reservado, como un dígito distinto del `0` de los espacios, un espacio en blanco o `-`.
+    SimbolosInvalidos(char),
+    /// Variante que indica que la búsqueda de las distribuciones de minas de un tablero parcial superó la cantidad máxima de pasos, por lo que se abandonó sin un resultado.
+    BusquedaExcedida,
 }
 
 impl fmt::Debug for ErrorMapa {
@@ -26,6 +38,25 @@ impl fmt::Debug for ErrorMapa {
             ErrorMapa::CeldaInexistente => {
                 write!(f, "Se intentó acceder a una celda que no existe")
             }
+            ErrorMapa::DemasiadasMinas(minas) => {
+                write!(f, "No hay celdas suficientes para ubicar {} minas.", minas)
+            }
+            ErrorMapa::DensidadInvalida => write!(f, "La densidad debe estar entre 0 y 1."),
+            ErrorMapa::GeneracionFallida => {
+                write!(
+                    f,
+                    "No se encontró un tablero que pueda resolverse sin adivinar."
+                )
+            }
+            ErrorMapa::PistasInconsistentes => {
+                write!(f, "Los números del tablero se contradicen entre sí.")
+            }
+            ErrorMapa::SimbolosInvalidos(c) => {
+                write!(f, "El caracter {} no puede usarse como símbolo.", c)
+            }
+            ErrorMapa::BusquedaExcedida => {
+                write!(f, "El tablero tiene demasiadas distribuciones de minas posibles para analizarlas.")
+            }
         }
     }
 }
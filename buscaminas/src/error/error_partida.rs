@@ -0,0 +1,35 @@
+//! # ErrorPartida
+//!
+//! `error_partida` permite representar todos los errores que pueden presentarse al jugar una [Partida](../tablero/partida/struct.Partida.html).
+use crate::error::error_mapa::ErrorMapa;
+use std::fmt;
+
+#[derive(PartialEq, Eq)]
+/// `enum` que representa los posibles errores a la hora de realizar una jugada sobre el tablero.
+pub enum ErrorPartida {
+    /// Variante que indica que se intentó realizar una jugada sobre una partida que ya fue ganada o perdida.
+    PartidaFinalizada,
+    /// Variante que indica que el tablero de la partida contiene celdas con varias minas, que el razonamiento sobre un [tablero parcial](../tablero/tablero_parcial/struct.TableroParcial.html) no contempla.
+    MinasConPeso,
+    /// Variante que encapsula los [errores provocados por el mapa][ErrorMapa], por ejemplo al jugar sobre una celda que no existe.
+    Mapa(ErrorMapa),
+}
+
+impl From<ErrorMapa> for ErrorPartida {
+    fn from(error: ErrorMapa) -> Self {
+        ErrorPartida::Mapa(error)
+    }
+}
+
+impl fmt::Debug for ErrorPartida {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ErrorPartida: ")?;
+        match self {
+            ErrorPartida::PartidaFinalizada => write!(f, "La partida ya ha finalizado."),
+            ErrorPartida::MinasConPeso => {
+                write!(f, "La deducción no admite celdas con varias minas.")
+            }
+            ErrorPartida::Mapa(e) => write!(f, "{:?}", e),
+        }
+    }
+}
@@ -7,17 +7,21 @@ const ESC_EXIT: &str = "\x1b[0;0m";
 const COLOR_ERROR: &str = "\x1b[31m";
 use crate::error::error_io::ErrorIO;
 use crate::error::error_mapa::ErrorMapa;
+use crate::error::error_partida::ErrorPartida;
 use std::fmt;
 use std::fmt::Debug;
 
 pub mod error_io;
 pub mod error_mapa;
+pub mod error_partida;
 
 pub enum Error {
     /// Variante que encapusla los [errores provocados por el mapa][ErrorMapa].
     Emapa(ErrorMapa),
     /// Variante que encapusla los [errores causados en la entrada/salida][ErrorIO] de información.
     Eio(ErrorIO),
+    /// Variante que encapusla los [errores producidos al jugar una partida][ErrorPartida].
+    Epartida(ErrorPartida),
 }
 
 impl From<ErrorIO> for Error {
@@ -32,12 +36,19 @@ impl From<ErrorMapa> for Error {
     }
 }
 
+impl From<ErrorPartida> for Error {
+    fn from(error: ErrorPartida) -> Self {
+        Error::Epartida(error)
+    }
+}
+
 impl Debug for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{COLOR_ERROR}")?;
         match self {
             Error::Emapa(e) => write!(f, "{:?}", e)?,
             Error::Eio(e) => write!(f, "{:?}", e)?,
+            Error::Epartida(e) => write!(f, "{:?}", e)?,
         }
         write!(f, "{ESC_EXIT}")
     }
@@ -1,19 +1,28 @@
 //! # Buscaminas
 //!
 //! Crate creado para el recuento de minas en un tablero de Buscaminas completo. El programa es capaz de contra el número de minas adyactenes a cada cuadrado vacío y los reemplaza por el resultado.
-//! Dicha resolución es publicada en el [archivo][ARCHIVO_SOLUCION].
+//! Dicha resolución se escribe en la salida estándar o en el archivo indicado con la opción `--output`.
 //!
 //! ## Contenido
-//! Dentro del `crate` se encuentran 3 módulos con componentes que permiten la funcionalidad requerida para este trabajo.
+//! Dentro del `crate` se encuentran 4 módulos con componentes que permiten la funcionalidad requerida para este trabajo.
 //!  - [Manejo de errores][error]
 //!     - Todo lo relacionado a los potenciales errores que pueden ocurrir en el programa y en el uso de los distintos ítems.
 //!  - [Manejo de la entrada y salida][my_io]
 //!     - Contiene ítems para simplifican la lectura del archivo pasado por línea de comandos y la escritua de la resolución en un archivo de texto.
+//!  - [Interfaz de línea de comandos][cli]
+//!     - Reconoce los argumentos del binario y ejecuta cada uno de sus subcomandos.
 //!  - [Construcción y solución del problema][tablero]
-//!     - Su contenido esta relacionado con los componentes que hacen al tablero, tanto la construcción del problema incial, como la resolución del problema.
+//!     - Su contenido esta relacionado con los componentes que hacen al tablero, tanto la construcción del problema incial, como la resolución del problema y el estado de una [partida][tablero::partida] jugable.
 //!
+pub mod cli;
 pub mod error;
 pub mod my_io;
 pub mod tablero;
 
+/// Nombre de archivo sugerido para guardar una solución.
 pub const ARCHIVO_SOLUCION: &str = "solucion.txt";
+
+/// Retorna la cantidad de hilos que conviene utilizar para repartir un trabajo entre los núcleos disponibles, o `1` si no puede determinarse.
+pub(crate) fn hilos_disponibles() -> usize {
+    std::thread::available_parallelism().map_or(1, |hilos| hilos.get())
+}
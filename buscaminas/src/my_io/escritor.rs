@@ -2,14 +2,15 @@
 //! `escritor` es un submódulo para facilitar la escritura de contenido en archivos de texto.
 
 use crate::error::error_io::ErrorIO;
+use crate::my_io::RUTA_ESTANDAR;
 use std::fmt::Display;
 use std::fs;
-use std::io::Write;
+use std::io::{self, BufWriter, Write};
 
-/// Estructura que capaz de escribir en un archivo
+/// Estructura que capaz de escribir en un archivo o en la salida estándar
 #[derive(Debug, PartialEq, Eq)]
 pub struct Escritor<'a> {
-    /// Ruta del archivo a escribir
+    /// Ruta del archivo a escribir. Si es [`RUTA_ESTANDAR`] se escribe en la salida estándar.
     path: &'a str,
 }
 
@@ -24,11 +25,11 @@ impl<'a> Escritor<'a> {
     /// let escritor = Escritor::new("./foo/filename.txt");
     /// # }
     /// ```
-    pub fn new(path: &str) -> Escritor {
+    pub fn new(path: &str) -> Escritor<'_> {
         Escritor { path }
     }
 
-    /// Escribe el item, que recibe por referencia, en el archivo con el que se haya [incializado][new] el [`Escritor`]. El item puede ser cualquiera que implemente el Trait [`Display`].
+    /// Escribe el item, que recibe por referencia, en el archivo con el que se haya [incializado][new] el [`Escritor`], o en la salida estándar si la ruta es [`RUTA_ESTANDAR`]. El item puede ser cualquiera que implemente el Trait [`Display`].
     ///
     /// [new]: ./struct.Escritor.html#method.new
     ///
@@ -54,10 +55,26 @@ impl<'a> Escritor<'a> {
     /// [`ErrorWrite`]: ../../error/enum.ErrorIO.html#variant.ErrorWrite
     /// [`ErrorFile`]: ../../error/enum.ErrorIO.html#variant.ErrorFile
     pub fn imprimir_item<T: Display>(&self, item: &T) -> Result<(), ErrorIO> {
-        let mut f = fs::File::create(self.path).map_err(ErrorIO::ErrorFile)?;
-        f.write_all(item.to_string().as_bytes())
+        let mut salida = self.abrir()?;
+        salida
+            .write_all(item.to_string().as_bytes())
+            .and_then(|_| salida.flush())
             .map_err(ErrorIO::ErrorWrite)
     }
+
+    /// Crea el archivo, o toma la salida estándar si la ruta es [`RUTA_ESTANDAR`], y retorna un escritor con buffer para escribir el contenido a medida que se produce. El buffer debe vaciarse con [`flush`][Write::flush] al terminar.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`ErrorFile`][ErrorIO::ErrorFile] si no puede crearse el archivo.
+    pub fn abrir(&self) -> Result<Box<dyn Write>, ErrorIO> {
+        Ok(match self.path {
+            RUTA_ESTANDAR => Box::new(io::stdout().lock()),
+            path => Box::new(BufWriter::new(
+                fs::File::create(path).map_err(ErrorIO::ErrorFile)?,
+            )),
+        })
+    }
 }
 
 #[cfg(test)]
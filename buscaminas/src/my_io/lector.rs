@@ -2,14 +2,14 @@
 //! `lector` es un submódulo para facilitar la lectura de contenido en archivos de texto.
 
 use crate::error::error_io::ErrorIO;
-use crate::tablero::builder::NUEVA_LINEA_ICONO;
+use crate::my_io::RUTA_ESTANDAR;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{self, BufRead, BufReader};
 
 #[derive(Debug, PartialEq, Eq)]
-/// Estructura capaz de leer contenido UTF-8 de un archivo de texto
+/// Estructura capaz de leer contenido UTF-8 de un archivo de texto o de la entrada estándar.
 pub struct Lector<'a> {
-    /// Ruta al archivo de texto de lectura.
+    /// Ruta al archivo de texto de lectura. Si es [`RUTA_ESTANDAR`] se lee de la entrada estándar.
     path: &'a str,
 }
 
@@ -26,11 +26,11 @@ impl<'a> Lector<'a> {
     ///
     /// # }
     /// ```
-    pub fn new(path: &str) -> Lector {
+    pub fn new(path: &str) -> Lector<'_> {
         Lector { path }
     }
 
-    ///Lee el archivo y retorna un [`String`] con el contenido del mismo.
+    ///Lee el archivo, o la entrada estándar si la ruta es [`RUTA_ESTANDAR`], y retorna un [`String`] con el contenido del mismo. Cada línea termina con un salto de línea (`\n`), sin importar cómo terminan las líneas en el archivo.
     ///
     /// # Ejemplo
     ///
@@ -57,14 +57,31 @@ impl<'a> Lector<'a> {
     ///
     ///
     pub fn leer_archivo(&self) -> Result<String, ErrorIO> {
-        let file = File::open(self.path).map_err(ErrorIO::ErrorFile)?;
-        let reader = BufReader::new(file);
+        Self::leer(self.abrir()?)
+    }
+
+    /// Abre el archivo, o la entrada estándar si la ruta es [`RUTA_ESTANDAR`], y retorna un lector con buffer para recorrerlo línea por línea sin cargarlo completo en memoria.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`ErrorFile`][ErrorIO::ErrorFile] si no puede abrirse el archivo.
+    pub fn abrir(&self) -> Result<Box<dyn BufRead>, ErrorIO> {
+        Ok(match self.path {
+            RUTA_ESTANDAR => Box::new(io::stdin().lock()),
+            path => Box::new(BufReader::new(
+                File::open(path).map_err(ErrorIO::ErrorFile)?,
+            )),
+        })
+    }
+
+    /// Lee todas las líneas del `reader` y las une en un [`String`], terminando cada una con un salto de línea.
+    fn leer<R: BufRead>(reader: R) -> Result<String, ErrorIO> {
         let mut palabras = String::new();
         for line in reader.lines() {
             let line = line.map_err(ErrorIO::ErrorBufReader)?;
             palabras.push_str(line.as_str());
-            // Separa los saltos de linea
-            palabras.push_str((NUEVA_LINEA_ICONO as char).to_string().as_str());
+            // Normaliza los saltos de linea
+            palabras.push('\n');
         }
         Ok(palabras)
     }
@@ -89,16 +106,22 @@ mod tests {
     fn lectura_archivo_valido_ascii() {
         let lector = Lector::new("./test_files/test1.txt");
         let resultado = lector.leer_archivo().expect("Error al leer el archivo.");
-        let esperado = ".**..*-..*..*-";
+        let esperado = ".**..*\n..*..*\n";
         assert_eq!(resultado, esperado);
 
         let sin_nueva_lineas = ".**..*..*..*";
         assert_ne!(resultado, sin_nueva_lineas);
 
-        let sin_nueva_linea_al_final = ".**..*-..*..*";
+        let sin_nueva_linea_al_final = ".**..*\n..*..*";
         assert_ne!(resultado, sin_nueva_linea_al_final);
     }
 
+    #[test]
+    fn lectura_normaliza_saltos_de_linea() {
+        let resultado = Lector::leer(io::Cursor::new("*.\r\n.*")).unwrap();
+        assert_eq!(resultado, "*.\n.*\n");
+    }
+
     #[test]
     fn abrir_archivo_inexistente() {
         let lector = Lector::new("./test_file/test_not_found.txt");
@@ -3,3 +3,6 @@
 
 pub mod escritor;
 pub mod lector;
+
+/// Ruta especial con la que el [`Lector`][lector::Lector] lee de la entrada estándar y el [`Escritor`][escritor::Escritor] escribe en la salida estándar.
+pub const RUTA_ESTANDAR: &str = "-";
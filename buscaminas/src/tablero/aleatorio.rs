@@ -0,0 +1,90 @@
+//! # Aleatorio
+//! `aleatorio` es un submódulo que contiene un generador de números pseudoaleatorios simple, implementado únicamente con la biblioteca estándar.
+
+/// Constante utilizada para avanzar el estado interno del generador en cada iteración.
+const INCREMENTO: u64 = 0x9E37_79B9_7F4A_7C15;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Generador de números pseudoaleatorios basado en el algoritmo *SplitMix64*.
+///
+/// Dada una misma semilla, la secuencia de números generada es siempre la misma, lo que permite reproducir los tableros generados.
+///
+/// ### Observación
+///
+/// El generador **no** es apto para usos criptográficos.
+pub struct Aleatorio {
+    /// Estado interno del generador.
+    estado: u64,
+}
+
+impl Aleatorio {
+    /// Construye un nuevo [`Aleatorio`] inicializado con la `semilla` que se pase por parámetro.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::aleatorio::Aleatorio;
+    /// #
+    /// # fn main() {
+    /// let mut a = Aleatorio::new(42);
+    /// let mut b = Aleatorio::new(42);
+    /// assert_eq!(a.siguiente(), b.siguiente());
+    /// # }
+    /// ```
+    pub fn new(semilla: u64) -> Aleatorio {
+        Aleatorio { estado: semilla }
+    }
+
+    /// Retorna el siguiente número de la secuencia pseudoaleatoria.
+    pub fn siguiente(&mut self) -> u64 {
+        self.estado = self.estado.wrapping_add(INCREMENTO);
+        let mut z = self.estado;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Retorna un número pseudoaleatorio en el rango `[0, limite)`. Si el `limite` es `0` se retorna `0`.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::aleatorio::Aleatorio;
+    /// #
+    /// # fn main() {
+    /// let mut aleatorio = Aleatorio::new(7);
+    /// assert!(aleatorio.rango(10) < 10);
+    /// # }
+    /// ```
+    pub fn rango(&mut self, limite: usize) -> usize {
+        ((self.siguiente() as u128 * limite as u128) >> 64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn misma_semilla_misma_secuencia() {
+        let mut a = Aleatorio::new(1234);
+        let mut b = Aleatorio::new(1234);
+        for _ in 0..100 {
+            assert_eq!(a.siguiente(), b.siguiente());
+        }
+    }
+
+    #[test]
+    fn distinta_semilla_distinta_secuencia() {
+        let mut a = Aleatorio::new(1);
+        let mut b = Aleatorio::new(2);
+        assert_ne!(a.siguiente(), b.siguiente());
+    }
+
+    #[test]
+    fn rango_dentro_de_los_limites() {
+        let mut aleatorio = Aleatorio::new(99);
+        for limite in 1..200 {
+            assert!(aleatorio.rango(limite) < limite);
+        }
+        assert_eq!(aleatorio.rango(0), 0);
+    }
+}
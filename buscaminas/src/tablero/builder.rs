@@ -5,26 +5,41 @@
 
 use crate::error::error_mapa::ErrorMapa;
 use crate::tablero::casillero::Casillero;
+use crate::tablero::celda_parcial::CeldaParcial;
+use crate::tablero::simbolos::Simbolos;
+use crate::tablero::tablero_parcial::TableroParcial;
+use crate::tablero::topologia::Topologia;
+use crate::tablero::vecindario::Vecindario;
 use crate::tablero::Tablero;
 
-/// Constante usada *internamente* para separar las filas.
-///
-/// # Aclaración
-///
-/// No es necesario que el archivo contenga dicho caracter para representar una nueva línea. Con hacer uso del salto de línea es suficiente.
-pub const NUEVA_LINEA_ICONO: u8 = b'-';
 /// Constante usada para representar a la mina.
 pub const MINA_ICONO: u8 = b'*';
+/// Constante usada para representar a una celda que contiene dos minas.
+pub const MINA_DOBLE_ICONO: u8 = b'@';
+/// Constante usada para representar a una celda que contiene tres minas.
+pub const MINA_TRIPLE_ICONO: u8 = b'&';
+/// Constante usada para representar a una anti-mina.
+pub const ANTIMINA_ICONO: u8 = b'~';
+/// Constante usada para representar a una pared.
+pub const PARED_ICONO: u8 = b'#';
 /// Constante usada para representar a un casillero vacío.
 pub const ESPACIO_ICONO: u8 = b'.';
 
 #[derive(Debug, PartialEq, Eq)]
 /// Estructura que contiene la representación del tablero en el formato inicial.
 ///
-/// Dicho formato debe respetar los carácteres predefinidos en las constantes que representan a las [minas][MINA_ICONO] y a los [espacios][ESPACIO_ICONO] si se desea construir satisfactoriamente el [`Tablero`].
+/// Dicho formato debe respetar los carácteres predefinidos en las constantes que representan a las [minas][MINA_ICONO] y a los [espacios][ESPACIO_ICONO] si se desea construir satisfactoriamente el [`Tablero`]. Las celdas que contienen [dos][MINA_DOBLE_ICONO] o [tres][MINA_TRIPLE_ICONO] minas y las [anti-minas][ANTIMINA_ICONO] tienen sus propios iconos. Las [paredes][PARED_ICONO] permiten representar tableros que no son rectangulares.
 pub struct TableroBuilder<'a> {
     /// `&str` que representa al tablero del juego. Es la base utilizada para poder construir el [Tablero](../struct.Tablero.html)
     casilleros: &'a str,
+    /// [`Topologia`] de los tableros a construir. Por defecto es [acotada][Topologia::Acotada].
+    topologia: Topologia,
+    /// [`Vecindario`] de los tableros a construir. Por defecto es el de [Moore][Vecindario::Moore].
+    vecindario: Vecindario,
+    /// Indica si las filas más cortas se completan con [paredes][PARED_ICONO] en lugar de rechazar el mapa. Por defecto es `false`.
+    rellenar: bool,
+    /// [`Simbolos`] con los que se leen las celdas del mapa. Los tableros construidos se muestran con los mismos símbolos. Por defecto son las constantes de este módulo.
+    simbolos: Simbolos,
 }
 
 impl<'a> TableroBuilder<'a> {
@@ -37,9 +52,9 @@ impl<'a> TableroBuilder<'a> {
     /// #
     /// # fn main() {
     /// // Tablero 4x3 con minas y vacios.
-    /// let builder = TableroBuilder::new("**.-..*-***-...-");
+    /// let builder = TableroBuilder::new("**.\n..*\n***\n...\n");
     /// // Tablero 2x3 con minas y vacios.
-    /// let builder = TableroBuilder::new("**.-..*-");
+    /// let builder = TableroBuilder::new("**.\n..*\n");
     /// // Tablero vacio
     /// let builder_error= TableroBuilder::new("");
     /// assert!(builder_error.is_err());
@@ -51,12 +66,103 @@ impl<'a> TableroBuilder<'a> {
     /// El [`TableroBuilder`] impide que se construyan [`Tablero`] sin celdas, por lo que lanzará [`MapaVacio`][ErrorMapa::MapaVacio] si se le pasa un string vacío.
     /// Esto se considera así ya que *no tiene sentido* resolver un juego cuyo mapa no existe.
     ///
-    pub fn new(casilleros: &str) -> Result<TableroBuilder, ErrorMapa> {
+    pub fn new(casilleros: &str) -> Result<TableroBuilder<'_>, ErrorMapa> {
         match casilleros.is_empty() {
-            false => Ok(TableroBuilder { casilleros }),
+            false => Ok(TableroBuilder {
+                casilleros,
+                topologia: Topologia::default(),
+                vecindario: Vecindario::default(),
+                rellenar: false,
+                simbolos: Simbolos::default(),
+            }),
             true => Err(ErrorMapa::MapaVacio),
         }
     }
+    /// Retorna el [`TableroBuilder`] configurado para construir tableros con la [`Topologia`] indicada.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::builder::TableroBuilder;
+    /// # use buscaminas::tablero::topologia::Topologia;
+    /// # use buscaminas::error::error_mapa::ErrorMapa;
+    /// #
+    /// # fn main() -> Result<(),ErrorMapa> {
+    /// // La mina de la esquina es adyacente a las celdas de los bordes opuestos.
+    /// let builder = TableroBuilder::new("*...\n....\n....\n")?;
+    /// let resultado = builder.con_topologia(Topologia::Toroidal).crear_tablero()?;
+    /// assert_eq!(resultado.to_string(), "*1.1\n11.1\n11.1\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn con_topologia(self, topologia: Topologia) -> TableroBuilder<'a> {
+        TableroBuilder { topologia, ..self }
+    }
+
+    /// Retorna el [`TableroBuilder`] configurado para construir tableros con el [`Vecindario`] indicado.
+    ///
+    /// En los tableros [hexagonales][Vecindario::Hexagonal] los espacios se ignoran, por lo que se acepta el mismo formato con el que se muestran: las celdas separadas por un espacio y las filas impares comenzando con un espacio.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::builder::TableroBuilder;
+    /// # use buscaminas::tablero::vecindario::Vecindario;
+    /// # use buscaminas::error::error_mapa::ErrorMapa;
+    /// #
+    /// # fn main() -> Result<(),ErrorMapa> {
+    /// let builder = TableroBuilder::new(". . .\n . * .\n. . .\n")?;
+    /// let resultado = builder.con_vecindario(Vecindario::Hexagonal).crear_tablero()?;
+    /// assert_eq!(resultado.to_string(), ". 1 1\n 1 * 1\n. 1 1\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn con_vecindario(self, vecindario: Vecindario) -> TableroBuilder<'a> {
+        TableroBuilder { vecindario, ..self }
+    }
+
+    /// Retorna el [`TableroBuilder`] configurado para aceptar filas de distinto ancho. Si `rellenar` es `true`, las filas más cortas se completan con [paredes][PARED_ICONO] hasta alcanzar el ancho de la más larga; si es `false`, un mapa que no es rectangular se rechaza.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::builder::TableroBuilder;
+    /// # use buscaminas::error::error_mapa::ErrorMapa;
+    /// #
+    /// # fn main() -> Result<(),ErrorMapa> {
+    /// let builder = TableroBuilder::new("*..\n.\n..\n")?;
+    /// assert_eq!(builder.crear_tablero(), Err(ErrorMapa::MapaMalformado));
+    /// let resultado = builder.con_relleno(true).crear_tablero()?;
+    /// assert_eq!(resultado.to_string(), "*1.\n1##\n..#\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn con_relleno(self, rellenar: bool) -> TableroBuilder<'a> {
+        TableroBuilder { rellenar, ..self }
+    }
+
+    /// Retorna el [`TableroBuilder`] configurado para leer el mapa con los [`Simbolos`] indicados. Los tableros construidos se muestran con esos mismos símbolos, salvo que se [indique][Tablero::con_simbolos] otro conjunto.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::builder::TableroBuilder;
+    /// # use buscaminas::tablero::simbolos::Simbolos;
+    /// # use buscaminas::error::error_mapa::ErrorMapa;
+    /// #
+    /// # fn main() -> Result<(),ErrorMapa> {
+    /// let simbolos = Simbolos { mina: '#', espacio: '_', pared: '|', ..Simbolos::default() };
+    /// let builder = TableroBuilder::new("#__\n_|_\n")?.con_simbolos(simbolos)?;
+    /// let resultado = builder.crear_tablero()?;
+    /// assert_eq!(resultado.to_string(), "#1_\n1|_\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`SimbolosInvalidos`][ErrorMapa::SimbolosInvalidos] si los caracteres de los `simbolos` se repiten o alguno está reservado, como los dígitos distintos del `0` de los espacios, los espacios en blanco o `-`.
+    pub fn con_simbolos(self, simbolos: Simbolos) -> Result<TableroBuilder<'a>, ErrorMapa> {
+        simbolos.validar()?;
+        Ok(TableroBuilder { simbolos, ..self })
+    }
+
     ///Construye el [`Tablero`] **resuelto** según el valor con el que fué construido previamente.
     ///
     /// # Ejemplos
@@ -66,7 +172,7 @@ impl<'a> TableroBuilder<'a> {
     /// #
     /// # fn main() -> Result<(),ErrorMapa> {
     /// // Tablero 2x2 con minas y espacios vacios.
-    /// let builder = TableroBuilder::new("*.-.*-")?;
+    /// let builder = TableroBuilder::new("*.\n.*\n")?;
     /// let resultado = builder.crear_tablero()?;
     /// println!("Solucion \n{}", resultado);
     /// # Ok(())
@@ -86,54 +192,274 @@ impl<'a> TableroBuilder<'a> {
         t.resolver()
     }
 
+    /// Construye un [`TableroParcial`] de dos dimensiones según el valor con el que fué construido previamente. Los tableros parciales de otras dimensiones se obtienen de la [vista][crate::tablero::partida::Partida::vista] de una partida.
+    ///
+    /// Además de los iconos de [minas][MINA_ICONO] y [espacios][ESPACIO_ICONO] sin minas adyacentes, se aceptan los dígitos `0` a `8` para los espacios descubiertos con minas adyacentes y el [icono][crate::tablero::partida::OCULTA_ICONO] de las celdas desconocidas y las [paredes][PARED_ICONO]. Las celdas con más de una mina y las anti-minas no se admiten.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::builder::TableroBuilder;
+    /// # use buscaminas::error::error_mapa::ErrorMapa;
+    /// #
+    /// # fn main() -> Result<(),ErrorMapa> {
+    /// let builder = TableroBuilder::new("?1.\n?2.\n?1.\n")?;
+    /// let parcial = builder.crear_tablero_parcial()?;
+    /// println!("{}", parcial);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errores
+    ///
+    /// Retorna los mismos errores que el [método] que crea tableros.
+    ///
+    /// [método]: ./struct.TableroBuilder.html#method.crear_tablero
+    pub fn crear_tablero_parcial(&self) -> Result<TableroParcial, ErrorMapa> {
+        let (ancho, largo, celdas) =
+            self.cargar_celdas(&self.filas(), Self::identificar_parcial)?;
+        self.vecindario.verificar(self.topologia, [ancho, largo])?;
+        Ok(TableroParcial::new(
+            [ancho, largo],
+            self.topologia,
+            self.vecindario.clone(),
+            self.simbolos.clone(),
+            celdas,
+        ))
+    }
+
+    /// Construye el [`Tablero`] de una dimensión **resuelto** según el valor con el que fué construido previamente. El contenido debe tener una única fila, que se trata como una tira de celdas.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::builder::TableroBuilder;
+    /// # use buscaminas::error::error_mapa::ErrorMapa;
+    /// #
+    /// # fn main() -> Result<(),ErrorMapa> {
+    /// let builder = TableroBuilder::new("*..*.*\n")?;
+    /// let resultado = builder.crear_tablero_1d()?;
+    /// assert_eq!(resultado.to_string(), "*11*2*\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errores
+    ///
+    /// Retorna los mismos errores que el [método] que crea tableros. Además, retorna [`MapaMalformado`][ErrorMapa::MapaMalformado] si el contenido tiene más de una fila.
+    ///
+    /// [método]: ./struct.TableroBuilder.html#method.crear_tablero
+    pub fn crear_tablero_1d(&self) -> Result<Tablero<1>, ErrorMapa> {
+        let (ancho, largo, mapa) = self.cargar_celdas(&self.filas(), Self::identificar)?;
+        match largo {
+            1 => self.armar_tablero([ancho], mapa)?.resolver(),
+            _ => Err(ErrorMapa::MapaMalformado),
+        }
+    }
+
+    /// Construye el [`Tablero`] de tres dimensiones **resuelto** según el valor con el que fué construido previamente.
+    ///
+    /// Cada capa del tablero tiene el mismo formato que un tablero de dos dimensiones y las capas se separan entre sí con una línea en blanco.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::builder::TableroBuilder;
+    /// # use buscaminas::error::error_mapa::ErrorMapa;
+    /// #
+    /// # fn main() -> Result<(),ErrorMapa> {
+    /// // Tablero de 2x2x2 con una mina en la primera capa.
+    /// let builder = TableroBuilder::new("*.\n..\n\n..\n..\n")?;
+    /// let resultado = builder.crear_tablero_3d()?;
+    /// assert_eq!(resultado.to_string(), "*1\n11\n\n11\n11\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errores
+    ///
+    /// Retorna los mismos errores que el [método] que crea tableros. Además, retorna [`MapaMalformado`][ErrorMapa::MapaMalformado] si las capas no tienen todas las mismas dimensiones y el builder no fue [configurado][TableroBuilder::con_relleno] para rellenarlas con paredes.
+    ///
+    /// [método]: ./struct.TableroBuilder.html#method.crear_tablero
+    pub fn crear_tablero_3d(&self) -> Result<Tablero<3>, ErrorMapa> {
+        let capas = self
+            .capas()
+            .iter()
+            .map(|capa| self.cargar_celdas(capa, Self::identificar))
+            .collect::<Result<Vec<_>, ErrorMapa>>()?;
+        let ancho = capas.iter().map(|capa| capa.0).max().unwrap_or(0);
+        let largo = capas.iter().map(|capa| capa.1).max().unwrap_or(0);
+        let alto = capas.len();
+        let mut mapa = Vec::with_capacity(ancho * largo * alto);
+        for capa in capas {
+            mapa.extend(self.ajustar_capa(capa, ancho, largo)?);
+        }
+        match alto {
+            0 => Err(ErrorMapa::MapaVacio),
+            _ => self.armar_tablero([ancho, largo, alto], mapa)?.resolver(),
+        }
+    }
+
+    /// Retorna las celdas de una `capa` de `ancho` x `largo`, formada por su ancho, su largo y sus celdas. Si la capa es más chica y el builder fue [configurado][TableroBuilder::con_relleno] para rellenar, se completa con paredes.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`MapaMalformado`][ErrorMapa::MapaMalformado] si la capa tiene otras dimensiones y no se rellena.
+    fn ajustar_capa(
+        &self,
+        capa: (usize, usize, Vec<Casillero>),
+        ancho: usize,
+        largo: usize,
+    ) -> Result<Vec<Casillero>, ErrorMapa> {
+        let (ancho_capa, largo_capa, celdas) = capa;
+        if (ancho_capa, largo_capa) == (ancho, largo) {
+            return Ok(celdas);
+        }
+        if !self.rellenar {
+            return Err(ErrorMapa::MapaMalformado);
+        }
+        let mut ajustada = Vec::with_capacity(ancho * largo);
+        for fila in celdas.chunks(ancho_capa.max(1)) {
+            ajustada.extend_from_slice(fila);
+            ajustada.resize(ajustada.len() + ancho - fila.len(), Casillero::Pared);
+        }
+        ajustada.resize(ancho * largo, Casillero::Pared);
+        Ok(ajustada)
+    }
+
+    /// Retorna `true` si el contenido en [`casilleros`] tiene más de una capa, es decir, si corresponde a un [`Tablero`] de tres dimensiones.
+    ///
+    /// [`casilleros`]: ./struct.TableroBuilder.html#structfield.casilleros
+    pub fn es_tridimensional(&self) -> bool {
+        self.capas().len() > 1
+    }
+
+    /// Separa el contenido de [`casilleros`] en las capas de un [`Tablero`] de tres dimensiones, delimitadas por líneas en blanco. Cada capa es el conjunto de sus filas.
+    ///
+    /// [`casilleros`]: ./struct.TableroBuilder.html#structfield.casilleros
+    fn capas(&self) -> Vec<Vec<&'a str>> {
+        self.filas()
+            .split(|fila| fila.is_empty())
+            .filter(|capa| !capa.is_empty())
+            .map(<[&str]>::to_vec)
+            .collect()
+    }
+
+    /// Separa el contenido de [`casilleros`] en sus filas, una por cada línea.
+    ///
+    /// [`casilleros`]: ./struct.TableroBuilder.html#structfield.casilleros
+    fn filas(&self) -> Vec<&'a str> {
+        self.casilleros.lines().collect()
+    }
+
     /// Construye un [`Tablero`] sin resolver, según el contenido en [`casilleros`].
     ///
     /// [`casilleros`]: ./struct.TableroBuilder.html#structfield.casilleros
     ///
     /// # Errores
     ///
-    /// Retorna un error en caso de querer construir un Tablero cuyas dimensiones no corresponden a un Tablero rectangular. Además, retorna los mismos errores que el método `identificar()`.
+    /// Retorna los mismos errores que el [método] que recorre las celdas.
     ///
+    /// [método]: ./struct.TableroBuilder.html#method.cargar_celdas
     fn cargar_tablero(&self) -> Result<Tablero, ErrorMapa> {
-        let (mut mapa, mut aux_ancho, mut ancho, mut largo) = (vec![], 0, 0, 0);
-
-        for _c in self.casilleros.as_bytes() {
-            let casillero = Self::identificar(*_c)?;
-            match casillero {
-                Casillero::NuevaLinea => {
-                    largo += 1;
-                    match ancho == aux_ancho {
-                        true => aux_ancho = 0,
-                        false => return Err(ErrorMapa::MapaMalformado),
-                    }
-                }
-                other => {
-                    mapa.push(other);
-                    aux_ancho += 1;
-                }
-            }
+        let (ancho, largo, mapa) = self.cargar_celdas(&self.filas(), Self::identificar)?;
+        self.armar_tablero([ancho, largo], mapa)
+    }
 
-            if largo == 0 {
-                ancho += 1;
+    /// Construye un [`Tablero`] sin resolver con las `dimensiones` y el `mapa` dados, utilizando la [`Topologia`] y el [`Vecindario`] configurados.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`MapaMalformado`][ErrorMapa::MapaMalformado] si el [`Vecindario`] no puede [utilizarse][Vecindario::verificar] con la [`Topologia`] y las dimensiones del tablero.
+    fn armar_tablero<const N: usize>(
+        &self,
+        dimensiones: [usize; N],
+        mapa: Vec<Casillero>,
+    ) -> Result<Tablero<N>, ErrorMapa> {
+        self.vecindario.verificar(self.topologia, dimensiones)?;
+        Ok(Tablero {
+            dimensiones,
+            topologia: self.topologia,
+            vecindario: self.vecindario.clone(),
+            simbolos: self.simbolos.clone(),
+            mapa,
+        })
+    }
+
+    /// Recorre las `filas` del contenido, identificando cada celda con la función `identificar`. Retorna el ancho, el largo y las celdas identificadas.
+    ///
+    /// # Errores
+    ///
+    /// Retorna los mismos errores que el [método] que une las filas y que la función `identificar`.
+    ///
+    /// [método]: ./struct.TableroBuilder.html#method.unir_filas
+    ///
+    fn cargar_celdas<T: Clone>(
+        &self,
+        filas: &[&str],
+        identificar: fn(&Self, char) -> Result<T, ErrorMapa>,
+    ) -> Result<(usize, usize, Vec<T>), ErrorMapa> {
+        let filas = filas
+            .iter()
+            .map(|fila| {
+                fila.chars()
+                    .filter(|c| !self.vecindario.es_separador(*c))
+                    .map(|c| identificar(self, c))
+                    .collect::<Result<Vec<T>, ErrorMapa>>()
+            })
+            .collect::<Result<Vec<_>, ErrorMapa>>()?;
+        self.unir_filas(filas, identificar(self, self.simbolos.pared)?)
+    }
+
+    /// Une las `filas` en un único vector de celdas. Retorna el ancho, el largo y las celdas.
+    ///
+    /// Si el builder fue [configurado][TableroBuilder::con_relleno] para rellenar, las filas más cortas se completan con la `pared` hasta alcanzar el ancho de la fila más larga.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`MapaMalformado`][ErrorMapa::MapaMalformado] si las filas no tienen todas el mismo ancho y no se rellenan.
+    fn unir_filas<T: Clone>(
+        &self,
+        filas: Vec<Vec<T>>,
+        pared: T,
+    ) -> Result<(usize, usize, Vec<T>), ErrorMapa> {
+        let ancho = match self.rellenar {
+            true => filas.iter().map(Vec::len).max().unwrap_or(0),
+            false => filas.first().map_or(0, Vec::len),
+        };
+        let largo = filas.len();
+        let mut celdas = Vec::with_capacity(ancho * largo);
+        for mut fila in filas {
+            if fila.len() != ancho && !self.rellenar {
+                return Err(ErrorMapa::MapaMalformado);
             }
+            fila.resize(ancho, pared.clone());
+            celdas.extend(fila);
         }
-        Ok(Tablero { largo, ancho, mapa })
+        Ok((ancho, largo, celdas))
     }
 
-    /// Identifica el tipo de casillero según el `u8` que se presente.
+    /// Identifica el tipo de casillero según el `char` que se presente, utilizando los [`Simbolos`] configurados.
     ///
     /// # Errores
     ///
-    /// Retorna [CaracterDesconocido] en caso de que no sea un `u8` que no pertenezca a los iconos aceptados.
+    /// Retorna [CaracterDesconocido] en caso de que no sea un `char` que no pertenezca a los símbolos aceptados.
     ///
     /// [CaracterDesconocido][ErrorMapa::CaracterDesconocido]
-    fn identificar(caracter: u8) -> Result<Casillero, ErrorMapa> {
-        match caracter {
-            MINA_ICONO => Ok(Casillero::Mina),
-            ESPACIO_ICONO => Ok(Casillero::Espacio(0)),
-            NUEVA_LINEA_ICONO => Ok(Casillero::NuevaLinea),
-            other => Err(ErrorMapa::CaracterDesconocido(other as char)),
-        }
+    fn identificar(&self, caracter: char) -> Result<Casillero, ErrorMapa> {
+        self.simbolos
+            .casillero(caracter)
+            .ok_or(ErrorMapa::CaracterDesconocido(caracter))
+    }
+
+    /// Identifica lo que se conoce de una celda de un [`TableroParcial`] según el `char` que se presente, utilizando los [`Simbolos`] configurados.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [CaracterDesconocido] en caso de que no sea un `char` que no pertenezca a los símbolos aceptados.
+    ///
+    /// [CaracterDesconocido][ErrorMapa::CaracterDesconocido]
+    fn identificar_parcial(&self, caracter: char) -> Result<CeldaParcial, ErrorMapa> {
+        self.simbolos
+            .celda_parcial(caracter)
+            .ok_or(ErrorMapa::CaracterDesconocido(caracter))
     }
 }
 
@@ -142,9 +468,13 @@ mod tests {
     use super::*;
     #[test]
     fn crear_tablero_builder() {
-        let resultado = TableroBuilder::new("...-...-...").unwrap();
+        let resultado = TableroBuilder::new("...\n...\n...").unwrap();
         let esperado = TableroBuilder {
-            casilleros: "...-...-...",
+            casilleros: "...\n...\n...",
+            topologia: Topologia::Acotada,
+            vecindario: Vecindario::Moore,
+            simbolos: Simbolos::default(),
+            rellenar: false,
         };
 
         assert_eq!(resultado, esperado);
@@ -157,32 +487,32 @@ mod tests {
     }
 
     #[test]
-    fn identificar_casillero_mediante_u8() {
-        let resultado = TableroBuilder::identificar(MINA_ICONO).unwrap();
-        let esperado = Casillero::Mina;
+    fn identificar_casillero_mediante_char() {
+        let builder = TableroBuilder::new(".").unwrap();
+        let resultado = builder.identificar(MINA_ICONO as char).unwrap();
+        let esperado = Casillero::Mina(1);
         assert_eq!(resultado, esperado);
 
-        let resultado = TableroBuilder::identificar(ESPACIO_ICONO).unwrap();
+        let resultado = builder.identificar(ESPACIO_ICONO as char).unwrap();
         let esperado = Casillero::Espacio(0);
         assert_eq!(resultado, esperado);
-
-        let resultado = TableroBuilder::identificar(NUEVA_LINEA_ICONO).unwrap();
-        let esperado = Casillero::NuevaLinea;
-        assert_eq!(resultado, esperado);
     }
 
     #[test]
-    fn identificar_con_u8_no_valido() {
-        let resultado = TableroBuilder::identificar(b'a');
+    fn identificar_con_char_no_valido() {
+        let builder = TableroBuilder::new(".").unwrap();
+        let resultado = builder.identificar('a');
         assert!(resultado.is_err());
     }
     #[test]
     fn cargar_tablero_sin_minas() {
-        let builder = TableroBuilder::new("...-...-").unwrap();
+        let builder = TableroBuilder::new("...\n...\n").unwrap();
         let resultado = builder.cargar_tablero().unwrap();
         let esperado = Tablero {
-            ancho: 3,
-            largo: 2,
+            dimensiones: [3, 2],
+            topologia: Topologia::Acotada,
+            vecindario: Vecindario::Moore,
+            simbolos: Simbolos::default(),
             mapa: vec![
                 Casillero::Espacio(0),
                 Casillero::Espacio(0),
@@ -196,39 +526,43 @@ mod tests {
     }
     #[test]
     fn cargar_tablero_con_minas() {
-        let builder = TableroBuilder::new("**-**-**-").unwrap();
+        let builder = TableroBuilder::new("**\n**\n**\n").unwrap();
         let resultado = builder.cargar_tablero().unwrap();
         let esperado = Tablero {
-            ancho: 2,
-            largo: 3,
+            dimensiones: [2, 3],
+            topologia: Topologia::Acotada,
+            vecindario: Vecindario::Moore,
+            simbolos: Simbolos::default(),
             mapa: vec![
-                Casillero::Mina,
-                Casillero::Mina,
-                Casillero::Mina,
-                Casillero::Mina,
-                Casillero::Mina,
-                Casillero::Mina,
+                Casillero::Mina(1),
+                Casillero::Mina(1),
+                Casillero::Mina(1),
+                Casillero::Mina(1),
+                Casillero::Mina(1),
+                Casillero::Mina(1),
             ],
         };
         assert_eq!(resultado, esperado);
     }
     #[test]
     fn cargar_tablero_mixto() {
-        let builder = TableroBuilder::new("*.*-.*.-*.*-").unwrap();
+        let builder = TableroBuilder::new("*.*\n.*.\n*.*\n").unwrap();
         let resultado = builder.cargar_tablero().unwrap();
         let esperado = Tablero {
-            ancho: 3,
-            largo: 3,
+            dimensiones: [3, 3],
+            topologia: Topologia::Acotada,
+            vecindario: Vecindario::Moore,
+            simbolos: Simbolos::default(),
             mapa: vec![
-                Casillero::Mina,
+                Casillero::Mina(1),
                 Casillero::Espacio(0),
-                Casillero::Mina,
+                Casillero::Mina(1),
                 Casillero::Espacio(0),
-                Casillero::Mina,
+                Casillero::Mina(1),
                 Casillero::Espacio(0),
-                Casillero::Mina,
+                Casillero::Mina(1),
                 Casillero::Espacio(0),
-                Casillero::Mina,
+                Casillero::Mina(1),
             ],
         };
         assert_eq!(resultado, esperado);
@@ -236,11 +570,13 @@ mod tests {
 
     #[test]
     fn crear_tablero_sin_minas() {
-        let builder = TableroBuilder::new("...-...-").unwrap();
+        let builder = TableroBuilder::new("...\n...\n").unwrap();
         let resultado = builder.crear_tablero().unwrap();
         let esperado = Tablero {
-            ancho: 3,
-            largo: 2,
+            dimensiones: [3, 2],
+            topologia: Topologia::Acotada,
+            vecindario: Vecindario::Moore,
+            simbolos: Simbolos::default(),
             mapa: vec![
                 Casillero::Espacio(0),
                 Casillero::Espacio(0),
@@ -254,55 +590,236 @@ mod tests {
     }
     #[test]
     fn crear_tablero_con_minas() {
-        let builder = TableroBuilder::new("**-**-**-").unwrap();
+        let builder = TableroBuilder::new("**\n**\n**\n").unwrap();
         let resultado = builder.crear_tablero().unwrap();
         let esperado = Tablero {
-            ancho: 2,
-            largo: 3,
+            dimensiones: [2, 3],
+            topologia: Topologia::Acotada,
+            vecindario: Vecindario::Moore,
+            simbolos: Simbolos::default(),
             mapa: vec![
-                Casillero::Mina,
-                Casillero::Mina,
-                Casillero::Mina,
-                Casillero::Mina,
-                Casillero::Mina,
-                Casillero::Mina,
+                Casillero::Mina(1),
+                Casillero::Mina(1),
+                Casillero::Mina(1),
+                Casillero::Mina(1),
+                Casillero::Mina(1),
+                Casillero::Mina(1),
             ],
         };
         assert_eq!(resultado, esperado);
     }
     #[test]
     fn crear_tablero_mixto() {
-        let builder = TableroBuilder::new("*.*-.*.-*.*-").unwrap();
+        let builder = TableroBuilder::new("*.*\n.*.\n*.*\n").unwrap();
         let resultado = builder.crear_tablero().unwrap();
         let esperado = Tablero {
-            ancho: 3,
-            largo: 3,
+            dimensiones: [3, 3],
+            topologia: Topologia::Acotada,
+            vecindario: Vecindario::Moore,
+            simbolos: Simbolos::default(),
             mapa: vec![
-                Casillero::Mina,
+                Casillero::Mina(1),
                 Casillero::Espacio(3),
-                Casillero::Mina,
+                Casillero::Mina(1),
                 Casillero::Espacio(3),
-                Casillero::Mina,
+                Casillero::Mina(1),
                 Casillero::Espacio(3),
-                Casillero::Mina,
+                Casillero::Mina(1),
                 Casillero::Espacio(3),
-                Casillero::Mina,
+                Casillero::Mina(1),
             ],
         };
         assert_eq!(resultado, esperado);
     }
 
+    #[test]
+    fn identificar_celda_parcial_mediante_char() {
+        let builder = TableroBuilder::new(".").unwrap();
+        let resultado = builder.identificar_parcial('?');
+        assert_eq!(resultado, Ok(CeldaParcial::Desconocida));
+        let resultado = builder.identificar_parcial(MINA_ICONO as char);
+        assert_eq!(resultado, Ok(CeldaParcial::Mina));
+        let resultado = builder.identificar_parcial(ESPACIO_ICONO as char);
+        assert_eq!(resultado, Ok(CeldaParcial::Descubierta(0)));
+        let resultado = builder.identificar_parcial('3');
+        assert_eq!(resultado, Ok(CeldaParcial::Descubierta(3)));
+        assert!(builder.identificar_parcial('9').is_err());
+    }
+
+    #[test]
+    fn leer_y_mostrar_con_simbolos_distintos() {
+        let entrada = Simbolos {
+            mina: 'X',
+            espacio: '0',
+            ..Simbolos::default()
+        };
+        let salida = Simbolos {
+            mina: '💣',
+            espacio: '⬜',
+            ..Simbolos::default()
+        };
+        let builder = TableroBuilder::new("X00\n000\n").unwrap();
+        assert_eq!(
+            builder.crear_tablero(),
+            Err(ErrorMapa::CaracterDesconocido('X'))
+        );
+        let resultado = builder
+            .con_simbolos(entrada)
+            .unwrap()
+            .crear_tablero()
+            .unwrap();
+        assert_eq!(resultado.to_string(), "X10\n110\n");
+        let resultado = resultado.con_simbolos(salida);
+        assert_eq!(resultado.to_string(), "💣1⬜\n11⬜\n");
+    }
+
+    #[test]
+    fn guion_como_simbolo() {
+        let simbolos = Simbolos {
+            espacio: '-',
+            ..Simbolos::default()
+        };
+        let builder = TableroBuilder::new("*--\r\n---\r\n").unwrap();
+        let resultado = builder.con_simbolos(simbolos);
+        assert_eq!(resultado.err(), Some(ErrorMapa::SimbolosInvalidos('-')));
+    }
+
+    #[test]
+    fn simbolos_repetidos_o_digitos() {
+        let builder = TableroBuilder::new("*.\n").unwrap();
+        let repetidos = Simbolos {
+            pared: '*',
+            ..Simbolos::default()
+        };
+        let resultado = builder.con_simbolos(repetidos);
+        assert_eq!(resultado.err(), Some(ErrorMapa::SimbolosInvalidos('*')));
+        let digito = Simbolos {
+            mina: '1',
+            ..Simbolos::default()
+        };
+        let resultado = TableroBuilder::new("1.\n").unwrap().con_simbolos(digito);
+        assert_eq!(resultado.err(), Some(ErrorMapa::SimbolosInvalidos('1')));
+    }
+
+    #[test]
+    fn crear_tablero_parcial_con_simbolos() {
+        let simbolos = Simbolos {
+            oculta: '▒',
+            mina: '💣',
+            ..Simbolos::default()
+        };
+        let builder = TableroBuilder::new("▒1\n💣1\n")
+            .unwrap()
+            .con_simbolos(simbolos)
+            .unwrap();
+        let resultado = builder.crear_tablero_parcial().unwrap();
+        assert_eq!(resultado.to_string(), "▒1\n💣1\n");
+    }
+
+    #[test]
+    fn crear_tablero_parcial() {
+        let builder = TableroBuilder::new("?1.\n*2.\n").unwrap();
+        let resultado = builder.crear_tablero_parcial().unwrap();
+        let esperado = TableroParcial::new(
+            [3, 2],
+            Topologia::Acotada,
+            Vecindario::Moore,
+            Simbolos::default(),
+            vec![
+                CeldaParcial::Desconocida,
+                CeldaParcial::Descubierta(1),
+                CeldaParcial::Descubierta(0),
+                CeldaParcial::Mina,
+                CeldaParcial::Descubierta(2),
+                CeldaParcial::Descubierta(0),
+            ],
+        );
+        assert_eq!(resultado, esperado);
+    }
+
+    #[test]
+    fn crear_tablero_parcial_malformado() {
+        let builder = TableroBuilder::new("?1.\n*2\n").unwrap();
+        assert_eq!(
+            builder.crear_tablero_parcial(),
+            Err(ErrorMapa::MapaMalformado)
+        );
+    }
+
     #[test]
     fn crear_tablero_malformado_no_rectangular() {
-        let builder = TableroBuilder::new("*.*-.*.-*.-").unwrap();
+        let builder = TableroBuilder::new("*.*\n.*.\n*.\n").unwrap();
         let resultado = builder.crear_tablero();
         assert!(resultado.is_err());
     }
 
     #[test]
     fn crear_tablero_malformado_caracter_no_identificable() {
-        let builder = TableroBuilder::new("*.*-.*.-*.a-").unwrap();
+        let builder = TableroBuilder::new("*.*\n.*.\n*.a\n").unwrap();
         let resultado = builder.crear_tablero();
         assert!(resultado.is_err());
     }
+
+    #[test]
+    fn crear_tablero_hexagonal() {
+        let casilleros = "* . . .\n . . . .\n. . * .\n . . . .\n";
+        let builder = TableroBuilder::new(casilleros).unwrap();
+        let builder = builder.con_vecindario(Vecindario::Hexagonal);
+        let resultado = builder.crear_tablero().unwrap();
+        assert_eq!(
+            resultado.to_string(),
+            "* 1 . .\n 1 1 1 .\n. 1 * 1\n . 1 1 .\n"
+        );
+        let mostrado = resultado.to_string();
+        let builder = TableroBuilder::new(&mostrado).unwrap();
+        let builder = builder.con_vecindario(Vecindario::Hexagonal);
+        let parcial = builder.crear_tablero_parcial().unwrap();
+        assert_eq!(parcial.to_string(), resultado.to_string());
+    }
+
+    #[test]
+    fn tablero_hexagonal_toroidal_con_filas_impares() {
+        let builder = TableroBuilder::new(". . .\n . * .\n. . .\n")
+            .unwrap()
+            .con_vecindario(Vecindario::Hexagonal)
+            .con_topologia(Topologia::Toroidal);
+        assert_eq!(builder.crear_tablero(), Err(ErrorMapa::MapaMalformado));
+        let resultado = builder.crear_tablero_parcial();
+        assert_eq!(resultado, Err(ErrorMapa::MapaMalformado));
+    }
+
+    #[test]
+    fn espacios_en_tablero_no_hexagonal() {
+        let builder = TableroBuilder::new("* .\n. .\n").unwrap();
+        let resultado = builder.crear_tablero();
+        assert_eq!(resultado, Err(ErrorMapa::CaracterDesconocido(' ')));
+    }
+
+    #[test]
+    fn crear_tablero_3d_con_capas_distintas() {
+        let builder = TableroBuilder::new("*.\n..\n\n...\n...\n").unwrap();
+        assert!(builder.es_tridimensional());
+        assert_eq!(builder.crear_tablero_3d(), Err(ErrorMapa::MapaMalformado));
+    }
+
+    #[test]
+    fn tablero_de_una_capa_no_es_tridimensional() {
+        let builder = TableroBuilder::new("*.\n..\n").unwrap();
+        assert!(!builder.es_tridimensional());
+        let resultado = builder.crear_tablero_3d().unwrap();
+        assert_eq!(resultado.to_string(), "*1\n11\n");
+    }
+
+    #[test]
+    fn rellenar_filas_cortas_con_paredes() {
+        let builder = TableroBuilder::new("?1\n?\n").unwrap().con_relleno(true);
+        let resultado = builder.crear_tablero_parcial().unwrap();
+        assert_eq!(resultado.to_string(), "?1\n?#\n");
+
+        let builder = TableroBuilder::new(".\n*..\n\n.*\n")
+            .unwrap()
+            .con_relleno(true);
+        let resultado = builder.crear_tablero_3d().unwrap();
+        assert_eq!(resultado.to_string(), "2##\n*21\n\n2*#\n###\n");
+    }
 }
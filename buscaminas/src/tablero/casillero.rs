@@ -3,33 +3,37 @@
 //! `casillero` es un submódulo que contiene los posibles tipos de celdas en un [`Tablero`].
 //!
 //! [`Tablero`]: ./struct.Tablero.html
-use crate::tablero::builder::{ESPACIO_ICONO, MINA_ICONO};
+use crate::tablero::simbolos::Simbolos;
 use std::fmt;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// `enum` cuyas variantes representan los distintos tipos de celdas presentes en el [`Tablero`] del buscaminas.
 ///
 /// [`Tablero`]: ./struct.Tablero.html
 pub enum Casillero {
-    /// Variante que representa a las minas.
-    Mina,
-    /// Variante que representa a los espacios vacios.
-    Espacio(u8),
-    /// Variante que indica una nueva linea, indicando el final de una de las filas. Necesario para la construccion del [`Tablero`].
-    ///
-    /// [`Tablero`]: ./struct.Tablero.html
-    NuevaLinea,
+    /// Variante que representa a las minas. Contiene la cantidad de minas de la celda, entre `1` y `3`, que es lo que aporta al recuento de sus celdas adyacentes.
+    Mina(u8),
+    /// Variante que representa a las anti-minas, celdas que restan una mina al recuento de sus celdas adyacentes.
+    AntiMina,
+    /// Variante que representa a una pared: una celda que no forma parte del tablero, pero que permite representar tableros con formas irregulares dentro de un mapa rectangular. Las paredes no suman al recuento, nunca se revelan y detienen la expansión de regiones de una [partida](../partida/struct.Partida.html).
+    Pared,
+    /// Variante que representa a los espacios vacios. Contiene la cantidad de minas adyacentes, que puede ser negativa si hay más anti-minas que minas alrededor.
+    Espacio(i32),
 }
 
 impl fmt::Display for Casillero {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(&Simbolos::default().representar(self))
+    }
+}
+
+impl Casillero {
+    /// Retorna la cantidad de minas que el casillero aporta al recuento de sus celdas adyacentes: su peso en caso de ser una [`Mina`][Casillero::Mina], `-1` en caso de ser una [`AntiMina`][Casillero::AntiMina] y `0` en cualquier otro caso.
+    pub fn minas(&self) -> i32 {
         match self {
-            Casillero::Espacio(x) => match *x == 0 {
-                true => write!(f, "{}", ESPACIO_ICONO as char),
-                false => write!(f, "{}", *x),
-            },
-            Casillero::Mina => write!(f, "{}", MINA_ICONO as char),
-            Casillero::NuevaLinea => writeln!(f),
+            Casillero::Mina(peso) => i32::from(*peso),
+            Casillero::AntiMina => -1,
+            _ => 0,
         }
     }
 }
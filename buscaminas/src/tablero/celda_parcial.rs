@@ -0,0 +1,18 @@
+//! CeldaParcial
+//!
+//! `celda_parcial` es un submódulo que contiene los posibles tipos de celdas en un [`TableroParcial`], es decir, lo que se conoce de cada celda en un tablero que no fue revelado por completo.
+//!
+//! [`TableroParcial`]: ../tablero_parcial/struct.TableroParcial.html
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// `enum` cuyas variantes representan la información disponible sobre una celda de un [`TableroParcial`](../tablero_parcial/struct.TableroParcial.html).
+pub enum CeldaParcial {
+    /// Variante que representa a una celda de la que no se conoce su contenido.
+    Desconocida,
+    /// Variante que representa a un espacio descubierto, junto con la cantidad de minas adyacentes que indica.
+    Descubierta(u8),
+    /// Variante que representa a una celda que se sabe con certeza que contiene una mina.
+    Mina,
+    /// Variante que representa a una pared, que no forma parte del tablero y por lo tanto no contiene minas.
+    Pared,
+}
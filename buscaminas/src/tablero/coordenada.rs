@@ -1,41 +1,58 @@
 //! Coordenada
 //! `coordenada` es un submódulo dedicado al `struct` que da noción de posición dentro del Tablero.
-#[derive(Debug, PartialEq, Eq)]
-/// `struct` que representa la posición dentro del Tablero de dos dimensiones.
+use std::array;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// `struct` que representa la posición dentro de un Tablero de `N` dimensiones.
 ///
-/// No es posible construir una Coordenadas2D con posiciones negativas.
+/// No es posible construir una coordenada con posiciones negativas.
 ///
 /// ### Observación
 /// A lo largo del programa `buscaminas` se utilizó el origen de coordenadas como la *esquina superior izquierda*. Dicha suposicion
-/// afecta al uso de este ítem.
-pub struct Coordenadas2D {
-    x: usize,
-    y: usize,
+/// afecta al uso de este ítem. La primera dimensión corresponde a las columnas, la segunda a las filas y la tercera a las capas.
+pub struct Coordenadas<const N: usize> {
+    posiciones: [usize; N],
 }
 
-impl Coordenadas2D {
-    ///Construye un par de [coordenadas][Coordenadas2D] con las posiciones (x,y).
-    ///
-    ///# Ejemplos
-    ///
-    ///```
-    /// # use buscaminas::tablero::coordenada::Coordenadas2D;
-    /// #
-    /// # fn main() {
-    /// // Coordenada en el origen
-    ///let resultado = Coordenadas2D::new(0,0);
-    /// // Coordenada en la posicion (5,2).
-    /// // Equivalente a la celda de la sexta columna y tercera fila.
-    ///let resultado = Coordenadas2D::new(5,2);
-    /// # }
-    /// ```
-    pub fn new(x: usize, y: usize) -> Coordenadas2D {
-        Coordenadas2D { x, y }
+/// Posición dentro de una tira de celdas.
+pub type Coordenadas1D = Coordenadas<1>;
+/// Posición dentro del Tablero clásico de dos dimensiones.
+pub type Coordenadas2D = Coordenadas<2>;
+/// Posición dentro de un Tablero de tres dimensiones, formado por varias capas.
+pub type Coordenadas3D = Coordenadas<3>;
+
+impl<const N: usize> From<[usize; N]> for Coordenadas<N> {
+    fn from(posiciones: [usize; N]) -> Self {
+        Coordenadas { posiciones }
+    }
+}
+
+impl<const N: usize> Coordenadas<N> {
+    /// Retorna la coordenada de la celda del `indice` dado en un mapa con las `dimensiones` indicadas, numerado fila por fila y, en tres dimensiones, capa por capa.
+    pub(crate) fn desde_indice(mut indice: usize, dimensiones: [usize; N]) -> Coordenadas<N> {
+        let mut posiciones = [0; N];
+        for (posicion, dimension) in posiciones.iter_mut().zip(dimensiones) {
+            *posicion = indice % dimension;
+            indice /= dimension;
+        }
+        Coordenadas { posiciones }
+    }
+
+    /// Retorna el indice de la celda en un mapa con las `dimensiones` indicadas, con la misma numeración que [`desde_indice`][Coordenadas::desde_indice], o `None` si la coordenada no pertenece al mapa.
+    pub(crate) fn indice(&self, dimensiones: [usize; N]) -> Option<usize> {
+        let mut indice = 0;
+        for (posicion, dimension) in self.posiciones.into_iter().zip(dimensiones).rev() {
+            if posicion >= dimension {
+                return None;
+            }
+            indice = indice * dimension + posicion;
+        }
+        Some(indice)
     }
 
-    /// Retorna una vector con las [coordenadas][Coordenadas2D] que sean adyacentes.
+    /// Retorna una vector con las [coordenadas][Coordenadas] que sean adyacentes en un mapa con las `dimensiones` dadas.
     ///
-    /// La *adyacencia* está dada por cualquier coordenada que este a un movimiento de distancia de la misma. Los movimientos válidos son en la dirección recta o diagonal.
+    /// La *adyacencia* está dada por cualquier coordenada que este a un movimiento de distancia de la misma. Los movimientos válidos son en la dirección recta o diagonal, por lo que una celda interior tiene 2 celdas adyacentes en una dimensión, 8 en dos y 26 en tres.
     ///
     ///# Ejemplos
     ///
@@ -44,9 +61,9 @@ impl Coordenadas2D {
     /// #
     /// # fn main() {
     /// // Asumiendo un mapa con un tamaño mínimo de 3x3
-    /// # let (ancho_mapa, largo_mapa) = (3,3);
+    /// # let dimensiones = [3,3];
     ///let coordenada = Coordenadas2D::new(0,0);
-    ///let resultado = coordenada.coordenadas_adyacentes(ancho_mapa,largo_mapa);
+    ///let resultado = coordenada.coordenadas_adyacentes(dimensiones);
     ///let esperados = vec![
     ///                     Coordenadas2D::new(0,1),
     ///                     Coordenadas2D::new(1,0),
@@ -55,7 +72,7 @@ impl Coordenadas2D {
     ///assert_eq!(resultado, esperados);
     ///
     ///let coordenada = Coordenadas2D::new(1,0);
-    ///let resultado = coordenada.coordenadas_adyacentes(ancho_mapa,largo_mapa);
+    ///let resultado = coordenada.coordenadas_adyacentes(dimensiones);
     ///let esperados = vec![
     ///                     Coordenadas2D::new(0,0),
     ///                     Coordenadas2D::new(0,1),
@@ -66,7 +83,7 @@ impl Coordenadas2D {
     ///assert_eq!(resultado, esperados);
     ///
     ///let coordenada = Coordenadas2D::new(1,1);
-    ///let resultado = coordenada.coordenadas_adyacentes(ancho_mapa,largo_mapa);
+    ///let resultado = coordenada.coordenadas_adyacentes(dimensiones);
     ///let esperados = vec![
     ///                     Coordenadas2D::new(0,0),
     ///                     Coordenadas2D::new(0,1),
@@ -82,23 +99,33 @@ impl Coordenadas2D {
     /// # }
     ///
     /// ```
-    pub fn coordenadas_adyacentes(&self, ancho: usize, largo: usize) -> Vec<Coordenadas2D> {
-        let coordenadas_x = Self::obtener_coordenada_adyacente(self.x, ancho);
-        let coordenadas_y = Self::obtener_coordenada_adyacente(self.y, largo);
-        let mut coordenadas = Vec::with_capacity(coordenadas_x.len() * coordenadas_y.len());
-        for _x in &coordenadas_x {
-            for _y in &coordenadas_y {
-                // Saltearse a si mismo
-                if *_x == self.x && *_y == self.y {
-                    continue;
-                }
-                coordenadas.push(Coordenadas2D::new(*_x, *_y))
-            }
-        }
-        coordenadas
+    ///
+    /// En tres dimensiones:
+    ///
+    ///```
+    /// # use buscaminas::tablero::coordenada::Coordenadas3D;
+    /// #
+    /// # fn main() {
+    ///let coordenada = Coordenadas3D::new(1,1,1);
+    ///assert_eq!(coordenada.coordenadas_adyacentes([3,3,3]).len(), 26);
+    ///
+    ///let esquina = Coordenadas3D::new(0,0,0);
+    ///assert_eq!(esquina.coordenadas_adyacentes([3,3,3]).len(), 7);
+    /// # }
+    /// ```
+    pub fn coordenadas_adyacentes(&self, dimensiones: [usize; N]) -> Vec<Coordenadas<N>> {
+        let opciones = array::from_fn(|d| {
+            Self::obtener_coordenada_adyacente(self.posiciones[d], dimensiones[d])
+        });
+        Self::combinar(&opciones)
+            .into_iter()
+            // Saltearse a si mismo
+            .filter(|c| c != self)
+            .collect()
     }
+
     /// Retorna una vector con las coordenadas adyacentes en una de las dimensiones.
-    fn obtener_coordenada_adyacente(coordenada: usize, dimension: usize) -> Vec<usize> {
+    pub(crate) fn obtener_coordenada_adyacente(coordenada: usize, dimension: usize) -> Vec<usize> {
         let mut coordenadas = Vec::with_capacity(2);
         if coordenada != 0 {
             coordenadas.push(coordenada - 1);
@@ -110,6 +137,82 @@ impl Coordenadas2D {
         coordenadas
     }
 
+    /// Retorna una vector con las [coordenadas][Coordenadas] que sean adyacentes en un mapa *toroidal*, es decir, uno en el que los bordes opuestos están unidos entre sí.
+    ///
+    /// La *adyacencia* es la misma que en el [método] de un mapa acotado, pero al salir por un borde se continúa por el borde opuesto. En mapas de una o dos celdas en alguna de sus dimensiones, las coordenadas que se repiten se incluyen una sola vez.
+    ///
+    /// [método]: ./struct.Coordenadas.html#method.coordenadas_adyacentes
+    ///
+    ///# Ejemplos
+    ///
+    ///```
+    /// # use buscaminas::tablero::coordenada::Coordenadas2D;
+    /// #
+    /// # fn main() {
+    ///let coordenada = Coordenadas2D::new(0,0);
+    ///let resultado = coordenada.coordenadas_adyacentes_toroidales([3,3]);
+    ///assert_eq!(resultado.len(), 8);
+    ///assert!(resultado.contains(&Coordenadas2D::new(2,2)));
+    /// # }
+    /// ```
+    pub fn coordenadas_adyacentes_toroidales(
+        &self,
+        dimensiones: [usize; N],
+    ) -> Vec<Coordenadas<N>> {
+        let opciones = array::from_fn(|d| {
+            Self::obtener_coordenada_adyacente_circular(self.posiciones[d], dimensiones[d]).to_vec()
+        });
+        let mut coordenadas = Vec::new();
+        for coordenada in Self::combinar(&opciones) {
+            if coordenada != *self && !coordenadas.contains(&coordenada) {
+                coordenadas.push(coordenada)
+            }
+        }
+        coordenadas
+    }
+
+    /// Retorna las coordenadas adyacentes en una de las dimensiones, uniendo el primer valor con el último.
+    fn obtener_coordenada_adyacente_circular(coordenada: usize, dimension: usize) -> [usize; 3] {
+        [
+            (coordenada + dimension - 1) % dimension,
+            coordenada,
+            (coordenada + 1) % dimension,
+        ]
+    }
+
+    /// Retorna todas las [coordenadas][Coordenadas] que se forman al tomar, para cada dimensión, una de las posiciones de `opciones`. Las coordenadas se ordenan según la primera dimensión, luego la segunda y así sucesivamente.
+    fn combinar(opciones: &[Vec<usize>; N]) -> Vec<Coordenadas<N>> {
+        let mut combinaciones = vec![[0; N]];
+        for (dimension, valores) in opciones.iter().enumerate() {
+            combinaciones = combinaciones
+                .iter()
+                .flat_map(|combinacion| {
+                    valores.iter().map(move |&valor| {
+                        let mut nueva = *combinacion;
+                        nueva[dimension] = valor;
+                        nueva
+                    })
+                })
+                .collect();
+        }
+        combinaciones.into_iter().map(Coordenadas::from).collect()
+    }
+
+    /// Retorna las posiciones de la coordenada en cada una de sus dimensiones.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::coordenada::Coordenadas3D;
+    /// #
+    /// # fn main() {
+    /// let coordenada = Coordenadas3D::new(2,0,1);
+    /// assert_eq!(coordenada.posiciones(), [2,0,1]);
+    /// # }
+    /// ```
+    pub fn posiciones(&self) -> [usize; N] {
+        self.posiciones
+    }
+
     /// Retorna la coordenada X, equivalente a la posición en las columnas del mapa.
     ///
     /// # Ejemplos
@@ -123,10 +226,38 @@ impl Coordenadas2D {
     /// # }
     /// ```
     pub fn x(&self) -> usize {
-        self.x
+        self.posiciones[0]
     }
+}
 
-    /// Retorna la coordenada X, equivalente a la posición en las columnas del mapa.
+impl Coordenadas<1> {
+    ///Construye una [coordenada][Coordenadas1D] con la posición x dentro de una tira de celdas.
+    pub fn new(x: usize) -> Coordenadas1D {
+        Coordenadas { posiciones: [x] }
+    }
+}
+
+impl Coordenadas<2> {
+    ///Construye un par de [coordenadas][Coordenadas2D] con las posiciones (x,y).
+    ///
+    ///# Ejemplos
+    ///
+    ///```
+    /// # use buscaminas::tablero::coordenada::Coordenadas2D;
+    /// #
+    /// # fn main() {
+    /// // Coordenada en el origen
+    ///let resultado = Coordenadas2D::new(0,0);
+    /// // Coordenada en la posicion (5,2).
+    /// // Equivalente a la celda de la sexta columna y tercera fila.
+    ///let resultado = Coordenadas2D::new(5,2);
+    /// # }
+    /// ```
+    pub fn new(x: usize, y: usize) -> Coordenadas2D {
+        Coordenadas { posiciones: [x, y] }
+    }
+
+    /// Retorna la coordenada Y, equivalente a la posición en las filas del mapa.
     ///
     /// # Ejemplos
     /// ```
@@ -139,7 +270,37 @@ impl Coordenadas2D {
     /// # }
     /// ```
     pub fn y(&self) -> usize {
-        self.y
+        self.posiciones[1]
+    }
+}
+
+impl Coordenadas<3> {
+    ///Construye una terna de [coordenadas][Coordenadas3D] con las posiciones (x,y,z).
+    ///
+    ///# Ejemplos
+    ///
+    ///```
+    /// # use buscaminas::tablero::coordenada::Coordenadas3D;
+    /// #
+    /// # fn main() {
+    /// // Celda de la sexta columna y tercera fila de la segunda capa.
+    ///let resultado = Coordenadas3D::new(5,2,1);
+    /// # }
+    /// ```
+    pub fn new(x: usize, y: usize, z: usize) -> Coordenadas3D {
+        Coordenadas {
+            posiciones: [x, y, z],
+        }
+    }
+
+    /// Retorna la coordenada Y, equivalente a la posición en las filas de cada capa.
+    pub fn y(&self) -> usize {
+        self.posiciones[1]
+    }
+
+    /// Retorna la coordenada Z, equivalente a la capa del mapa.
+    pub fn z(&self) -> usize {
+        self.posiciones[2]
     }
 }
 
@@ -149,7 +310,7 @@ mod tests {
     #[test]
     fn crear_nueva_coordenada() {
         let coordenada = Coordenadas2D::new(2, 3);
-        let esperado = Coordenadas2D { x: 2, y: 3 };
+        let esperado = Coordenadas { posiciones: [2, 3] };
 
         assert_eq!(coordenada, esperado);
     }
@@ -164,41 +325,41 @@ mod tests {
         // 6,7,8
 
         //Borde oeste -> x = 0
-        let resultado = Coordenadas2D::obtener_coordenada_adyacente(0, ancho);
-        let esperado = vec![0, 1];
+        let resultado = Coordenadas1D::obtener_coordenada_adyacente(0, ancho);
+        let esperado = [0, 1];
         for (r, e) in resultado.iter().zip(esperado.iter()) {
             assert_eq!(r, e);
         }
 
         //Borde este -> x = ancho - 1
-        let resultado = Coordenadas2D::obtener_coordenada_adyacente(ancho - 1, ancho);
-        let esperado = vec![ancho - 2, ancho - 1];
+        let resultado = Coordenadas1D::obtener_coordenada_adyacente(ancho - 1, ancho);
+        let esperado = [ancho - 2, ancho - 1];
         for (r, e) in resultado.iter().zip(esperado.iter()) {
             assert_eq!(r, e);
         }
         //Borde norte -> y = 0 // Analogo a borde oeste
-        let resultado = Coordenadas2D::obtener_coordenada_adyacente(0, largo);
-        let esperado = vec![0, 1];
+        let resultado = Coordenadas1D::obtener_coordenada_adyacente(0, largo);
+        let esperado = [0, 1];
         for (r, e) in resultado.iter().zip(esperado.iter()) {
             assert_eq!(r, e);
         }
         //Borde sur -> y = largo - 1 // Analogo a borde este
-        let resultado = Coordenadas2D::obtener_coordenada_adyacente(largo - 1, largo);
-        let esperado = vec![largo - 2, largo - 1];
+        let resultado = Coordenadas1D::obtener_coordenada_adyacente(largo - 1, largo);
+        let esperado = [largo - 2, largo - 1];
         for (r, e) in resultado.iter().zip(esperado.iter()) {
             assert_eq!(r, e);
         }
 
         //Coordenada interna -> x o y, distinto a los casos anteriores
-        let resultado = Coordenadas2D::obtener_coordenada_adyacente(ancho - 2, ancho);
-        let esperado = vec![ancho - 3, ancho - 2, ancho - 1];
+        let resultado = Coordenadas1D::obtener_coordenada_adyacente(ancho - 2, ancho);
+        let esperado = [ancho - 3, ancho - 2, ancho - 1];
         for (r, e) in resultado.iter().zip(esperado.iter()) {
             assert_eq!(r, e);
         }
 
         //Coordenada interna -> x o y, distinto a los casos anteriores
-        let resultado = Coordenadas2D::obtener_coordenada_adyacente(largo - 2, largo);
-        let esperado = vec![largo - 3, largo - 2, largo - 1];
+        let resultado = Coordenadas1D::obtener_coordenada_adyacente(largo - 2, largo);
+        let esperado = [largo - 3, largo - 2, largo - 1];
         for (r, e) in resultado.iter().zip(esperado.iter()) {
             assert_eq!(r, e);
         }
@@ -213,8 +374,8 @@ mod tests {
         // (0,1) , (1,1) , (2,1)
         // (0,2) , (1,2) , (2,2)
         let coordenada = Coordenadas2D::new(0, 0);
-        let resultado = coordenada.coordenadas_adyacentes(ancho, largo);
-        let esperado = vec![
+        let resultado = coordenada.coordenadas_adyacentes([ancho, largo]);
+        let esperado = [
             Coordenadas2D::new(0, 1),
             Coordenadas2D::new(1, 0),
             Coordenadas2D::new(1, 1),
@@ -224,8 +385,8 @@ mod tests {
         }
 
         let coordenada = Coordenadas2D::new(1, 0);
-        let resultado = coordenada.coordenadas_adyacentes(ancho, largo);
-        let esperado = vec![
+        let resultado = coordenada.coordenadas_adyacentes([ancho, largo]);
+        let esperado = [
             Coordenadas2D::new(0, 0),
             Coordenadas2D::new(0, 1),
             Coordenadas2D::new(1, 1),
@@ -237,8 +398,8 @@ mod tests {
         }
 
         let coordenada = Coordenadas2D::new(2, 0);
-        let resultado = coordenada.coordenadas_adyacentes(ancho, largo);
-        let esperado = vec![
+        let resultado = coordenada.coordenadas_adyacentes([ancho, largo]);
+        let esperado = [
             Coordenadas2D::new(1, 0),
             Coordenadas2D::new(1, 1),
             Coordenadas2D::new(2, 1),
@@ -250,8 +411,8 @@ mod tests {
         }
 
         let coordenada = Coordenadas2D::new(0, 1);
-        let resultado = coordenada.coordenadas_adyacentes(ancho, largo);
-        let esperado = vec![
+        let resultado = coordenada.coordenadas_adyacentes([ancho, largo]);
+        let esperado = [
             Coordenadas2D::new(0, 0),
             Coordenadas2D::new(0, 2),
             Coordenadas2D::new(1, 0),
@@ -263,8 +424,8 @@ mod tests {
         }
 
         let coordenada = Coordenadas2D::new(1, 1);
-        let resultado = coordenada.coordenadas_adyacentes(ancho, largo);
-        let esperado = vec![
+        let resultado = coordenada.coordenadas_adyacentes([ancho, largo]);
+        let esperado = [
             Coordenadas2D::new(0, 0),
             Coordenadas2D::new(0, 1),
             Coordenadas2D::new(0, 2),
@@ -279,8 +440,8 @@ mod tests {
         }
 
         let coordenada = Coordenadas2D::new(2, 1);
-        let resultado = coordenada.coordenadas_adyacentes(ancho, largo);
-        let esperado = vec![
+        let resultado = coordenada.coordenadas_adyacentes([ancho, largo]);
+        let esperado = [
             Coordenadas2D::new(1, 0),
             Coordenadas2D::new(1, 1),
             Coordenadas2D::new(1, 2),
@@ -292,8 +453,8 @@ mod tests {
         }
 
         let coordenada = Coordenadas2D::new(0, 2);
-        let resultado = coordenada.coordenadas_adyacentes(ancho, largo);
-        let esperado = vec![
+        let resultado = coordenada.coordenadas_adyacentes([ancho, largo]);
+        let esperado = [
             Coordenadas2D::new(0, 1),
             Coordenadas2D::new(1, 1),
             Coordenadas2D::new(1, 2),
@@ -303,8 +464,8 @@ mod tests {
         }
 
         let coordenada = Coordenadas2D::new(1, 2);
-        let resultado = coordenada.coordenadas_adyacentes(ancho, largo);
-        let esperado = vec![
+        let resultado = coordenada.coordenadas_adyacentes([ancho, largo]);
+        let esperado = [
             Coordenadas2D::new(0, 1),
             Coordenadas2D::new(0, 2),
             Coordenadas2D::new(1, 1),
@@ -316,8 +477,8 @@ mod tests {
         }
 
         let coordenada = Coordenadas2D::new(2, 2);
-        let resultado = coordenada.coordenadas_adyacentes(ancho, largo);
-        let esperado = vec![
+        let resultado = coordenada.coordenadas_adyacentes([ancho, largo]);
+        let esperado = [
             Coordenadas2D::new(1, 1),
             Coordenadas2D::new(1, 2),
             Coordenadas2D::new(2, 1),
@@ -326,4 +487,52 @@ mod tests {
             assert_eq!(r, e);
         }
     }
+
+    #[test]
+    fn obtener_coordenadas_adyacentes_toroidales() {
+        let coordenada = Coordenadas2D::new(0, 0);
+        let resultado = coordenada.coordenadas_adyacentes_toroidales([3, 3]);
+        let esperado = [
+            Coordenadas2D::new(2, 2),
+            Coordenadas2D::new(2, 0),
+            Coordenadas2D::new(2, 1),
+            Coordenadas2D::new(0, 2),
+            Coordenadas2D::new(0, 1),
+            Coordenadas2D::new(1, 2),
+            Coordenadas2D::new(1, 0),
+            Coordenadas2D::new(1, 1),
+        ];
+        assert_eq!(resultado, esperado);
+    }
+
+    #[test]
+    fn adyacentes_toroidales_sin_repetidos() {
+        let coordenada = Coordenadas2D::new(0, 0);
+        let resultado = coordenada.coordenadas_adyacentes_toroidales([2, 1]);
+        assert_eq!(resultado, [Coordenadas2D::new(1, 0)]);
+    }
+
+    #[test]
+    fn obtener_coordenadas_adyacentes_en_una_dimension() {
+        let coordenada = Coordenadas1D::new(0);
+        assert_eq!(
+            coordenada.coordenadas_adyacentes([3]),
+            [Coordenadas1D::new(1)]
+        );
+        let coordenada = Coordenadas1D::new(1);
+        let esperado = [Coordenadas1D::new(0), Coordenadas1D::new(2)];
+        assert_eq!(coordenada.coordenadas_adyacentes([3]), esperado);
+    }
+
+    #[test]
+    fn obtener_coordenadas_adyacentes_en_tres_dimensiones() {
+        let coordenada = Coordenadas3D::new(0, 0, 1);
+        let resultado = coordenada.coordenadas_adyacentes([2, 1, 2]);
+        let esperado = [
+            Coordenadas3D::new(0, 0, 0),
+            Coordenadas3D::new(1, 0, 0),
+            Coordenadas3D::new(1, 0, 1),
+        ];
+        assert_eq!(resultado, esperado);
+    }
 }
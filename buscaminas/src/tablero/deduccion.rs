@@ -0,0 +1,32 @@
+//! Deduccion
+//!
+//! `deduccion` es un submódulo que contiene a [`Deduccion`], el resultado de razonar lógicamente sobre un [`TableroParcial`].
+//!
+//! [`TableroParcial`]: ../tablero_parcial/struct.TableroParcial.html
+use crate::tablero::coordenada::Coordenadas;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Estructura que contiene las celdas desconocidas cuyo contenido pudo determinarse con certeza.
+pub struct Deduccion<const N: usize = 2> {
+    /// [Coordenadas] de las celdas que con certeza contienen una mina.
+    minas: Vec<Coordenadas<N>>,
+    /// [Coordenadas] de las celdas que con certeza no contienen una mina.
+    seguras: Vec<Coordenadas<N>>,
+}
+
+impl<const N: usize> Deduccion<N> {
+    /// Construye una nueva [`Deduccion`] con las celdas que son minas y las que son seguras.
+    pub(crate) fn new(minas: Vec<Coordenadas<N>>, seguras: Vec<Coordenadas<N>>) -> Deduccion<N> {
+        Deduccion { minas, seguras }
+    }
+
+    /// Retorna las [coordenadas][Coordenadas] de las celdas que con certeza contienen una mina.
+    pub fn minas(&self) -> &[Coordenadas<N>] {
+        &self.minas
+    }
+
+    /// Retorna las [coordenadas][Coordenadas] de las celdas que con certeza no contienen una mina.
+    pub fn seguras(&self) -> &[Coordenadas<N>] {
+        &self.seguras
+    }
+}
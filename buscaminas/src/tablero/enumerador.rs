@@ -0,0 +1,183 @@
+//! Enumerador
+//!
+//! `enumerador` es un submódulo que contiene a [`Enumerador`], encargado de recorrer todas las distribuciones de minas que cumplen un conjunto de [restricciones][Restriccion].
+use crate::error::error_mapa::ErrorMapa;
+use crate::tablero::restriccion::Restriccion;
+
+/// Constante que indica la cantidad máxima de celdas que se asignan al buscar las distribuciones de minas de un tablero parcial. Como la cantidad de asignaciones crece de forma exponencial con las celdas desconocidas, la búsqueda se abandona al alcanzarla.
+pub(crate) const PASOS_MAXIMOS: usize = 1_000_000;
+
+/// Estructura que recorre, mediante *backtracking* y sin recursión, todas las formas de asignar minas a un conjunto de celdas desconocidas de manera que se cumplan todas las [restricciones][Restriccion].
+pub(crate) struct Enumerador<'a> {
+    /// Indices de las celdas a asignar, en el orden en que se asignan.
+    celdas: &'a [usize],
+    /// Restricciones que deben cumplir las asignaciones.
+    restricciones: &'a [Restriccion],
+    /// Indices de las restricciones que involucran a cada celda del tablero.
+    por_celda: Vec<Vec<usize>>,
+}
+
+impl<'a> Enumerador<'a> {
+    /// Construye un nuevo [`Enumerador`] de las `celdas` indicadas, para un tablero con `total_celdas` celdas.
+    pub(crate) fn new(
+        celdas: &'a [usize],
+        restricciones: &'a [Restriccion],
+        total_celdas: usize,
+    ) -> Enumerador<'a> {
+        let mut por_celda = vec![vec![]; total_celdas];
+        for (i, restriccion) in restricciones.iter().enumerate() {
+            for &celda in restriccion.celdas() {
+                por_celda[celda].push(i);
+            }
+        }
+        Enumerador {
+            celdas,
+            restricciones,
+            por_celda,
+        }
+    }
+
+    /// Recorre todas las asignaciones válidas de las celdas, invocando a `visitar` con las celdas `conocidas` completas en cada una de ellas. Al finalizar, las celdas recorridas vuelven a ser desconocidas.
+    ///
+    /// Cada celda asignada consume uno de los `pasos` disponibles. Si `visitar` retorna `false` el recorrido se interrumpe y se retorna `Ok(false)`.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`BusquedaExcedida`][ErrorMapa::BusquedaExcedida] si se agotan los `pasos` antes de terminar el recorrido.
+    pub(crate) fn recorrer<F>(
+        &self,
+        conocidas: &mut [Option<bool>],
+        pasos: &mut usize,
+        visitar: &mut F,
+    ) -> Result<bool, ErrorMapa>
+    where
+        F: FnMut(&[Option<bool>]) -> bool,
+    {
+        let completo = self.asignar(conocidas, pasos, visitar);
+        for &celda in self.celdas {
+            conocidas[celda] = None;
+        }
+        completo
+    }
+
+    /// Asigna las celdas en orden, primero como seguras y luego como minas, retrocediendo cada vez que una asignación no es consistente con las restricciones. Las celdas asignadas se mantienen como una pila explícita, formada por las primeras `j` celdas.
+    fn asignar<F>(
+        &self,
+        conocidas: &mut [Option<bool>],
+        pasos: &mut usize,
+        visitar: &mut F,
+    ) -> Result<bool, ErrorMapa>
+    where
+        F: FnMut(&[Option<bool>]) -> bool,
+    {
+        let mut j = 0;
+        let mut consistente = true;
+        loop {
+            if consistente && j == self.celdas.len() {
+                if !visitar(conocidas) {
+                    return Ok(false);
+                }
+                consistente = false;
+            }
+            if consistente {
+                conocidas[self.celdas[j]] = Some(false);
+                j += 1;
+            } else if !self.retroceder(&mut j, conocidas) {
+                return Ok(true);
+            }
+            *pasos = pasos.checked_sub(1).ok_or(ErrorMapa::BusquedaExcedida)?;
+            consistente = self.es_consistente(self.celdas[j - 1], conocidas);
+        }
+    }
+
+    /// Desapila las celdas asignadas como minas y cambia la última asignada como segura para que pase a ser una mina. Retorna `false` si ya no quedan asignaciones por probar.
+    fn retroceder(&self, j: &mut usize, conocidas: &mut [Option<bool>]) -> bool {
+        while *j > 0 && conocidas[self.celdas[*j - 1]] == Some(true) {
+            conocidas[self.celdas[*j - 1]] = None;
+            *j -= 1;
+        }
+        match *j {
+            0 => false,
+            _ => {
+                conocidas[self.celdas[*j - 1]] = Some(true);
+                true
+            }
+        }
+    }
+
+    /// Retorna `true` si todas las restricciones que involucran a la `celda` todavía pueden cumplirse.
+    fn es_consistente(&self, celda: usize, conocidas: &[Option<bool>]) -> bool {
+        self.por_celda[celda]
+            .iter()
+            .all(|&r| self.restricciones[r].admite(conocidas))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recorrer_todas_las_asignaciones() {
+        // Entre las celdas 0, 1 y 2 hay una mina, y entre 1 y 2 no hay ninguna.
+        let restricciones = [
+            Restriccion::new(vec![0, 1, 2], 1),
+            Restriccion::new(vec![1, 2, 3], 0),
+        ];
+        let celdas = [0, 1, 2];
+        let enumerador = Enumerador::new(&celdas, &restricciones, 4);
+        let mut conocidas = vec![None, None, None, Some(false)];
+        let mut soluciones = vec![];
+        let mut pasos = PASOS_MAXIMOS;
+        let completo = enumerador.recorrer(&mut conocidas, &mut pasos, &mut |c| {
+            soluciones.push(c.to_vec());
+            true
+        });
+        assert_eq!(completo, Ok(true));
+        let esperado = vec![Some(true), Some(false), Some(false), Some(false)];
+        assert_eq!(soluciones, vec![esperado]);
+        assert_eq!(conocidas, vec![None, None, None, Some(false)]);
+    }
+
+    #[test]
+    fn interrumpir_recorrido() {
+        let restricciones = [Restriccion::new(vec![0, 1, 2], 1)];
+        let celdas = [0, 1, 2];
+        let enumerador = Enumerador::new(&celdas, &restricciones, 3);
+        let mut conocidas = vec![None; 3];
+        let mut visitadas = 0;
+        let mut pasos = PASOS_MAXIMOS;
+        let completo = enumerador.recorrer(&mut conocidas, &mut pasos, &mut |_| {
+            visitadas += 1;
+            visitadas < 2
+        });
+        assert_eq!(completo, Ok(false));
+        assert_eq!(visitadas, 2);
+        assert_eq!(conocidas, vec![None; 3]);
+    }
+
+    #[test]
+    fn agotar_los_pasos() {
+        let celdas: Vec<usize> = (0..30).collect();
+        let enumerador = Enumerador::new(&celdas, &[], 30);
+        let mut conocidas = vec![None; 30];
+        let mut pasos = 100;
+        let resultado = enumerador.recorrer(&mut conocidas, &mut pasos, &mut |_| true);
+        assert_eq!(resultado, Err(ErrorMapa::BusquedaExcedida));
+        assert_eq!(pasos, 0);
+        assert_eq!(conocidas, vec![None; 30]);
+    }
+
+    #[test]
+    fn recorrer_sin_celdas() {
+        let enumerador = Enumerador::new(&[], &[], 0);
+        let mut visitadas = 0;
+        let mut pasos = PASOS_MAXIMOS;
+        let completo = enumerador.recorrer(&mut [], &mut pasos, &mut |_| {
+            visitadas += 1;
+            true
+        });
+        assert_eq!(completo, Ok(true));
+        assert_eq!(visitadas, 1);
+    }
+}
@@ -0,0 +1,111 @@
+//! # Estadisticas
+//! `estadisticas` es un submódulo que contiene a [`Estadisticas`], el resumen del contenido de un [`Tablero`] resuelto.
+//!
+//! [`Tablero`]: ../struct.Tablero.html
+use crate::tablero::casillero::Casillero;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Estructura que contiene la cantidad de celdas de cada tipo de un [`Tablero`](../struct.Tablero.html) resuelto.
+pub struct Estadisticas {
+    /// Cantidad total de celdas del tablero, incluyendo las paredes.
+    celdas: usize,
+    /// Cantidad de minas del tablero, sumando el peso de cada celda con minas.
+    minas: usize,
+    /// Cantidad de anti-minas del tablero.
+    antiminas: usize,
+    /// Cantidad de paredes del tablero.
+    paredes: usize,
+    /// Cantidad de espacios del tablero.
+    espacios: usize,
+    /// Cantidad de espacios sin minas adyacentes.
+    espacios_vacios: usize,
+    /// Mayor cantidad de minas adyacentes a un espacio.
+    maximo: i32,
+}
+
+impl fmt::Display for Estadisticas {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Celdas: {}", self.celdas)?;
+        writeln!(f, "Minas: {}", self.minas)?;
+        writeln!(f, "Anti-minas: {}", self.antiminas)?;
+        writeln!(f, "Paredes: {}", self.paredes)?;
+        writeln!(f, "Espacios: {}", self.espacios)?;
+        writeln!(f, "Espacios sin minas adyacentes: {}", self.espacios_vacios)?;
+        writeln!(f, "Máximo de minas adyacentes: {}", self.maximo)?;
+        writeln!(f, "Densidad: {:.2}%", self.densidad() * 100.0)
+    }
+}
+
+impl Estadisticas {
+    /// Calcula las [`Estadisticas`] de las celdas del `mapa` de un tablero resuelto.
+    pub(crate) fn calcular(mapa: &[Casillero]) -> Estadisticas {
+        let mut estadisticas = Estadisticas {
+            celdas: mapa.len(),
+            minas: 0,
+            antiminas: 0,
+            paredes: 0,
+            espacios: 0,
+            espacios_vacios: 0,
+            maximo: 0,
+        };
+        for casillero in mapa {
+            match casillero {
+                Casillero::Mina(peso) => estadisticas.minas += *peso as usize,
+                Casillero::AntiMina => estadisticas.antiminas += 1,
+                Casillero::Pared => estadisticas.paredes += 1,
+                Casillero::Espacio(minas) => {
+                    estadisticas.espacios += 1;
+                    estadisticas.espacios_vacios += usize::from(*minas == 0);
+                    estadisticas.maximo = estadisticas.maximo.max(*minas);
+                }
+            }
+        }
+        estadisticas
+    }
+
+    /// Retorna la cantidad total de celdas del tablero, incluyendo las paredes.
+    pub fn celdas(&self) -> usize {
+        self.celdas
+    }
+
+    /// Retorna la cantidad de minas del tablero, sumando el peso de cada celda con minas.
+    pub fn minas(&self) -> usize {
+        self.minas
+    }
+
+    /// Retorna la cantidad de espacios del tablero.
+    pub fn espacios(&self) -> usize {
+        self.espacios
+    }
+
+    /// Retorna la proporción de minas sobre las celdas que no son paredes, o `0` si todas las celdas son paredes.
+    pub fn densidad(&self) -> f64 {
+        match self.celdas - self.paredes {
+            0 => 0.0,
+            jugables => self.minas as f64 / jugables as f64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tablero::builder::TableroBuilder;
+
+    #[test]
+    fn estadisticas_de_un_tablero() {
+        let tablero = TableroBuilder::new("*@.\n.#~\n")
+            .unwrap()
+            .crear_tablero()
+            .unwrap();
+        let estadisticas = tablero.estadisticas();
+        assert_eq!(estadisticas.celdas(), 6);
+        assert_eq!(estadisticas.minas(), 3);
+        assert_eq!(estadisticas.espacios(), 2);
+        assert_eq!(estadisticas.densidad(), 0.6);
+        assert_eq!(
+            estadisticas.to_string(),
+            "Celdas: 6\nMinas: 3\nAnti-minas: 1\nParedes: 1\nEspacios: 2\nEspacios sin minas adyacentes: 0\nMáximo de minas adyacentes: 3\nDensidad: 60.00%\n"
+        );
+    }
+}
@@ -0,0 +1,18 @@
+//! EstadoCelda
+//!
+//! `estado_celda` es un submódulo que contiene los posibles estados de visibilidad de una celda durante una [`Partida`].
+//!
+//! [`Partida`]: ../partida/struct.Partida.html
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// `enum` cuyas variantes representan lo que el jugador conoce de cada celda del [`Tablero`].
+///
+/// [`Tablero`]: ../struct.Tablero.html
+pub enum EstadoCelda {
+    /// Variante que representa a una celda que todavía no fue descubierta.
+    Oculta,
+    /// Variante que representa a una celda descubierta, cuyo [`Casillero`](../casillero/enum.Casillero.html) es visible.
+    Revelada,
+    /// Variante que representa a una celda oculta que el jugador marcó como posible mina. No puede ser revelada mientras esté marcada.
+    Marcada,
+}
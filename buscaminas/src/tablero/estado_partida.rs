@@ -0,0 +1,16 @@
+//! EstadoPartida
+//!
+//! `estado_partida` es un submódulo que contiene los posibles estados de una [`Partida`].
+//!
+//! [`Partida`]: ../partida/struct.Partida.html
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// `enum` cuyas variantes indican si la [`Partida`](../partida/struct.Partida.html) sigue en juego o si ya finalizó.
+pub enum EstadoPartida {
+    /// Variante que indica que todavía quedan espacios por descubrir y no se reveló ninguna mina.
+    EnCurso,
+    /// Variante que indica que se revelaron todos los espacios sin minas.
+    Ganada,
+    /// Variante que indica que se reveló una mina.
+    Perdida,
+}
@@ -0,0 +1,313 @@
+//! # Flujo
+//! `flujo` es un submódulo que contiene a [`Flujo`], que resuelve un tablero de dos dimensiones fila por fila a medida que se lee, sin cargarlo completo en memoria.
+
+use crate::error::error_io::ErrorIO;
+use crate::error::error_mapa::ErrorMapa;
+use crate::error::Error;
+use crate::tablero::casillero::Casillero;
+use crate::tablero::coordenada::Coordenadas2D;
+use crate::tablero::simbolos::Simbolos;
+use crate::tablero::topologia::Topologia;
+use crate::tablero::vecindario::Vecindario;
+use std::collections::VecDeque;
+use std::io::{BufRead, Write};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+/// Estructura que resuelve un tablero [acotado][Topologia::Acotada] leyendo sus filas de un [`BufRead`] y escribiendo cada fila resuelta en un [`Write`] apenas se conocen todas sus adyacentes.
+///
+/// Solo se conservan en memoria las filas de una ventana deslizante: la fila que se resuelve y las que están a su [alcance][Vecindario] hacia arriba y hacia abajo, es decir, tres filas con el vecindario de Moore. La memoria utilizada no depende de la cantidad de filas del tablero.
+///
+/// Como las filas se escriben antes de conocer el resto del tablero, todas las celdas se alinean a un mismo ancho elegido de antemano: el del mayor recuento que puede producir el vecindario, con tres minas en cada adyacente, o el del menor, con una anti-mina en cada una. Con el vecindario de Moore son dos caracteres, por lo que la salida es la misma que la del [`Tablero`](super::Tablero) cuando este también alinea sus celdas a dos caracteres, es decir, cuando tiene algún recuento de dos dígitos o negativo.
+pub struct Flujo {
+    /// [`Vecindario`] con el que se cuentan las minas adyacentes.
+    vecindario: Vecindario,
+    /// [`Simbolos`] con los que se leen las filas.
+    simbolos: Simbolos,
+    /// [`Simbolos`] con los que se escriben las filas resueltas.
+    simbolos_salida: Simbolos,
+}
+
+impl Flujo {
+    /// Retorna el [`Flujo`] configurado para contar las minas adyacentes según el `vecindario` indicado.
+    pub fn con_vecindario(self, vecindario: Vecindario) -> Flujo {
+        Flujo { vecindario, ..self }
+    }
+
+    /// Retorna el [`Flujo`] configurado para leer y escribir las filas con los `simbolos` indicados.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`SimbolosInvalidos`][ErrorMapa::SimbolosInvalidos] si los caracteres de los `simbolos` se repiten o alguno está reservado, como los dígitos distintos del `0` de los espacios, los espacios en blanco o `-`.
+    pub fn con_simbolos(self, simbolos: Simbolos) -> Result<Flujo, ErrorMapa> {
+        simbolos.validar()?;
+        Ok(Flujo {
+            simbolos_salida: simbolos.clone(),
+            simbolos,
+            ..self
+        })
+    }
+
+    /// Retorna el [`Flujo`] configurado para escribir las filas resueltas con los `simbolos` indicados, sin modificar los de lectura.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`SimbolosInvalidos`][ErrorMapa::SimbolosInvalidos] si los caracteres de los `simbolos` se repiten o alguno está reservado, como los dígitos distintos del `0` de los espacios, los espacios en blanco o `-`.
+    pub fn con_simbolos_salida(self, simbolos_salida: Simbolos) -> Result<Flujo, ErrorMapa> {
+        simbolos_salida.validar()?;
+        Ok(Flujo {
+            simbolos_salida,
+            ..self
+        })
+    }
+
+    /// Lee las filas del tablero de la `entrada` y escribe cada una, resuelta, en la `salida` tan pronto como se leyeron las filas de las que depende. Retorna la cantidad de filas resueltas.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::flujo::Flujo;
+    /// # use buscaminas::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut salida = vec![];
+    /// let filas = Flujo::default().resolver(".*.\n...\n..*\n".as_bytes(), &mut salida)?;
+    /// assert_eq!(filas, 3);
+    /// assert_eq!(salida, b" 1  *  1\n 1  2  2\n .  1  *\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errores
+    ///
+    /// - Retorna [`MapaVacio`][ErrorMapa::MapaVacio] si la `entrada` no contiene filas.
+    /// - Retorna [`MapaMalformado`][ErrorMapa::MapaMalformado] si alguna fila está vacía o no tiene el ancho de la primera.
+    /// - Retorna [`CaracterDesconocido`][ErrorMapa::CaracterDesconocido] si alguna celda no corresponde a los [`Simbolos`] configurados.
+    /// - Retorna los errores producidos al leer la `entrada` o al escribir la `salida`.
+    pub fn resolver<R: BufRead, W: Write>(
+        &self,
+        entrada: R,
+        salida: &mut W,
+    ) -> Result<usize, Error> {
+        let alcance = self.vecindario.alcance();
+        let mut ventana = VecDeque::with_capacity(2 * alcance + 1);
+        let (mut primera, mut filas, mut ancho) = (0, 0usize, None);
+        for linea in entrada.lines() {
+            let fila = self.leer_fila(&linea.map_err(ErrorIO::ErrorBufReader)?, ancho)?;
+            ancho = Some(fila.len());
+            ventana.push_back(fila);
+            filas += 1;
+            if let Some(y) = filas.checked_sub(alcance + 1) {
+                self.escribir_fila(y, &ventana, primera, salida)?;
+                while primera + alcance <= y {
+                    ventana.pop_front();
+                    primera += 1;
+                }
+            }
+        }
+        if filas == 0 {
+            return Err(ErrorMapa::MapaVacio.into());
+        }
+        for y in filas.saturating_sub(alcance)..filas {
+            self.escribir_fila(y, &ventana, primera, salida)?;
+        }
+        salida.flush().map_err(ErrorIO::ErrorWrite)?;
+        Ok(filas)
+    }
+
+    /// Identifica las celdas de la `linea` leída. Si se conoce el `ancho` del tablero, la fila debe tener ese ancho.
+    fn leer_fila(&self, linea: &str, ancho: Option<usize>) -> Result<Vec<Casillero>, ErrorMapa> {
+        let fila = linea
+            .chars()
+            .filter(|c| !self.vecindario.es_separador(*c))
+            .map(|c| {
+                self.simbolos
+                    .casillero(c)
+                    .ok_or(ErrorMapa::CaracterDesconocido(c))
+            })
+            .collect::<Result<Vec<Casillero>, ErrorMapa>>()?;
+        match ancho {
+            _ if fila.is_empty() => Err(ErrorMapa::MapaMalformado),
+            Some(ancho) if ancho != fila.len() => Err(ErrorMapa::MapaMalformado),
+            _ => Ok(fila),
+        }
+    }
+
+    /// Resuelve la fila `y` del tablero, que se encuentra en la `ventana` cuya primera fila es la fila `primera` del tablero, y la escribe en la `salida`.
+    fn escribir_fila<W: Write>(
+        &self,
+        y: usize,
+        ventana: &VecDeque<Vec<Casillero>>,
+        primera: usize,
+        salida: &mut W,
+    ) -> Result<(), ErrorIO> {
+        let fila = self.resolver_fila(y, ventana, primera);
+        write!(salida, "{}", self.mostrar_fila(y, &fila)).map_err(ErrorIO::ErrorWrite)
+    }
+
+    /// Retorna la fila `y` del tablero con el recuento de minas adyacentes de cada espacio. Las adyacentes que no están en la `ventana` quedan fuera del tablero, ya que la ventana contiene todas las filas al alcance del vecindario que existen.
+    fn resolver_fila(
+        &self,
+        y: usize,
+        ventana: &VecDeque<Vec<Casillero>>,
+        primera: usize,
+    ) -> Vec<Casillero> {
+        let celda = |c: &Coordenadas2D| ventana.get(c.y().checked_sub(primera)?)?.get(c.x());
+        let ancho = ventana[y - primera].len();
+        ventana[y - primera]
+            .iter()
+            .enumerate()
+            .map(|(x, casillero)| match casillero {
+                Casillero::Espacio(_) => Casillero::Espacio(
+                    self.vecindario
+                        .coordenadas_adyacentes(
+                            &Coordenadas2D::new(x, y),
+                            [ancho, usize::MAX],
+                            Topologia::Acotada,
+                        )
+                        .iter()
+                        .filter_map(celda)
+                        .map(|c| c.minas())
+                        .sum(),
+                ),
+                otro => *otro,
+            })
+            .collect()
+    }
+
+    /// Retorna el texto de la `fila` resuelta, la fila `y` del tablero, terminado en un salto de línea.
+    fn mostrar_fila(&self, y: usize, fila: &[Casillero]) -> String {
+        let ancho_celda = self.ancho_celda();
+        let mut texto = String::new();
+        for (x, casillero) in fila.iter().enumerate() {
+            let celda = self.simbolos_salida.representar(casillero);
+            let separador = match self
+                .vecindario
+                .prefijo((y % 2) * fila.len() + x, fila.len())
+            {
+                "" if ancho_celda > 1 && x != 0 => " ",
+                prefijo => prefijo,
+            };
+            texto.push_str(&format!("{}{:>ancho_celda$}", separador, celda));
+        }
+        texto.push('\n');
+        texto
+    }
+
+    /// Retorna el ancho, en caracteres, al que se alinean todas las celdas: el del mayor recuento que puede producir el vecindario, con tres minas en cada adyacente, o el del menor, con una anti-mina en cada una.
+    fn ancho_celda(&self) -> usize {
+        let adyacentes = self.vecindario.tamanio();
+        let mayor = (3 * adyacentes).to_string().len();
+        let menor = match adyacentes {
+            0 => 1,
+            n => n.to_string().len() + 1,
+        };
+        mayor.max(menor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tablero::builder::TableroBuilder;
+
+    fn resolver(flujo: &Flujo, casilleros: &str) -> Result<String, Error> {
+        let mut salida = vec![];
+        flujo.resolver(casilleros.as_bytes(), &mut salida)?;
+        Ok(String::from_utf8(salida).unwrap())
+    }
+
+    /// Quita la alineación de las celdas de la `salida` de un tablero no hexagonal en el que todas las celdas ocupan un caracter.
+    fn sin_alinear(salida: &str) -> String {
+        salida.replace(' ', "")
+    }
+
+    #[test]
+    fn resuelve_igual_que_el_tablero() {
+        let casilleros = "*..*.\n.*...\n...*.\n*...*\n.#.*.\n..@..\n";
+        for vecindario in [
+            Vecindario::Moore,
+            Vecindario::VonNeumann,
+            Vecindario::Caballo,
+            Vecindario::Personalizado(vec![(0, -3), (1, 3)]),
+        ] {
+            let flujo = Flujo::default().con_vecindario(vecindario.clone());
+            let builder = TableroBuilder::new(casilleros).unwrap();
+            let tablero = builder.con_vecindario(vecindario).crear_tablero().unwrap();
+            let resultado = resolver(&flujo, casilleros).unwrap();
+            assert_eq!(sin_alinear(&resultado), tablero.to_string());
+        }
+    }
+
+    #[test]
+    fn alinea_como_el_tablero_con_recuentos_de_dos_digitos() {
+        for casilleros in ["@@@\n@.@\n@@@\n", "*.~\n...\n", "~~~\n~.~\n~~~\n"] {
+            let tablero = TableroBuilder::new(casilleros).unwrap().crear_tablero();
+            let resultado = resolver(&Flujo::default(), casilleros).unwrap();
+            assert_eq!(resultado, tablero.unwrap().to_string());
+        }
+    }
+
+    #[test]
+    fn resuelve_recuentos_de_tres_digitos() {
+        let desplazamientos = (-3..=3)
+            .flat_map(|dx| (-3..=3).map(move |dy| (dx, dy)))
+            .collect();
+        let flujo = Flujo::default().con_vecindario(Vecindario::Personalizado(desplazamientos));
+        let mut casilleros = "&&&&&&&\n".repeat(7);
+        casilleros.replace_range(27..28, ".");
+        let resultado = resolver(&flujo, &casilleros).unwrap();
+        assert_eq!(
+            resultado.lines().nth(3),
+            Some("  &   &   & 144   &   &   &")
+        );
+    }
+
+    #[test]
+    fn resuelve_tableros_hexagonales() {
+        let flujo = Flujo::default().con_vecindario(Vecindario::Hexagonal);
+        let resultado = resolver(&flujo, ". . .\n . * .\n. . .\n").unwrap();
+        assert_eq!(resultado, " .  1  1\n  1  *  1\n .  1  1\n");
+    }
+
+    #[test]
+    fn la_ventana_no_crece_con_las_filas() {
+        let flujo = Flujo::default();
+        let casilleros = "*.\n".repeat(1000);
+        let resultado = resolver(&flujo, &casilleros).unwrap();
+        let esperado = format!("*2\n{}*2\n", "*3\n".repeat(998));
+        assert_eq!(sin_alinear(&resultado), esperado);
+    }
+
+    #[test]
+    fn alinea_todas_las_filas_al_mismo_ancho() {
+        let flujo = Flujo::default();
+        let resultado = resolver(&flujo, "*.~\n...\n").unwrap();
+        assert_eq!(resultado, " *  .  ~\n 1  . -1\n");
+        let flujo = Flujo::default().con_vecindario(Vecindario::Personalizado(vec![(1, 0)]));
+        let resultado = resolver(&flujo, ".~\n.&\n").unwrap();
+        assert_eq!(resultado, "-1  ~\n 3  &\n");
+    }
+
+    #[test]
+    fn simbolos_de_salida_reservados() {
+        for reservado in [' ', '-'] {
+            let simbolos = Simbolos {
+                mina: reservado,
+                ..Simbolos::default()
+            };
+            let resultado = Flujo::default().con_simbolos_salida(simbolos);
+            assert_eq!(
+                resultado.err(),
+                Some(ErrorMapa::SimbolosInvalidos(reservado))
+            );
+        }
+    }
+
+    #[test]
+    fn tableros_invalidos() {
+        let flujo = Flujo::default();
+        assert!(resolver(&flujo, "").is_err());
+        assert!(resolver(&flujo, "..\n.\n").is_err());
+        assert!(resolver(&flujo, "..\n\n..\n").is_err());
+        assert!(resolver(&flujo, "..\nx.\n").is_err());
+    }
+}
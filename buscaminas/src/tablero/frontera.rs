@@ -0,0 +1,167 @@
+//! Frontera
+//!
+//! `frontera` es un submódulo que contiene a [`Frontera`], un grupo de celdas desconocidas vinculadas entre sí por los espacios descubiertos de un [`TableroParcial`].
+//!
+//! [`TableroParcial`]: ../tablero_parcial/struct.TableroParcial.html
+use crate::error::error_mapa::ErrorMapa;
+use crate::tablero::enumerador::Enumerador;
+use crate::tablero::restriccion::Restriccion;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Estructura que contiene un grupo conexo de celdas desconocidas adyacentes a espacios descubiertos, junto con la cantidad de distribuciones de minas válidas sobre ellas.
+///
+/// Las celdas de fronteras distintas no comparten ninguna [`Restriccion`], por lo que sus distribuciones de minas son independientes entre sí.
+pub(crate) struct Frontera {
+    /// Indices de las celdas de la frontera.
+    celdas: Vec<usize>,
+    /// `soluciones[k]` es la cantidad de distribuciones válidas con exactamente `k` minas.
+    soluciones: Vec<f64>,
+    /// `minas_por_celda[k][j]` es la cantidad de distribuciones válidas con exactamente `k` minas en las que `celdas[j]` es una mina. Solo se reserva espacio para las cantidades de minas que tienen alguna distribución válida.
+    minas_por_celda: Vec<Option<Vec<f64>>>,
+}
+
+impl Frontera {
+    /// Separa las celdas desconocidas involucradas en alguna de las `restricciones` en grupos conexos, es decir, tales que dos celdas pertenecen al mismo grupo si están unidas por una cadena de restricciones.
+    pub(crate) fn separar(
+        restricciones: &[Restriccion],
+        conocidas: &[Option<bool>],
+    ) -> Vec<Vec<usize>> {
+        let mut por_celda = vec![vec![]; conocidas.len()];
+        for (i, restriccion) in restricciones.iter().enumerate() {
+            for &celda in restriccion
+                .celdas()
+                .iter()
+                .filter(|&&c| conocidas[c].is_none())
+            {
+                por_celda[celda].push(i);
+            }
+        }
+        let mut visitadas = vec![false; conocidas.len()];
+        let mut grupos = vec![];
+        for inicio in 0..conocidas.len() {
+            if !visitadas[inicio] && !por_celda[inicio].is_empty() {
+                grupos.push(Self::recorrer_grupo(
+                    inicio,
+                    restricciones,
+                    &por_celda,
+                    &mut visitadas,
+                ));
+            }
+        }
+        grupos
+    }
+
+    /// Construye la [`Frontera`] de las `celdas` indicadas, enumerando todas sus distribuciones de minas válidas. Cada celda asignada durante la enumeración consume uno de los `pasos` disponibles.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`BusquedaExcedida`][ErrorMapa::BusquedaExcedida] si se agotan los `pasos` antes de terminar la enumeración.
+    pub(crate) fn enumerar(
+        celdas: Vec<usize>,
+        restricciones: &[Restriccion],
+        conocidas: &mut [Option<bool>],
+        pasos: &mut usize,
+    ) -> Result<Frontera, ErrorMapa> {
+        let mut soluciones = vec![0.0; celdas.len() + 1];
+        let mut minas_por_celda = vec![None; celdas.len() + 1];
+        let enumerador = Enumerador::new(&celdas, restricciones, conocidas.len());
+        enumerador.recorrer(conocidas, pasos, &mut |asignacion| {
+            let es_mina = |c: &usize| asignacion[*c] == Some(true);
+            let k = celdas.iter().filter(|c| es_mina(c)).count();
+            soluciones[k] += 1.0;
+            let minas = minas_por_celda[k].get_or_insert_with(|| vec![0.0; celdas.len()]);
+            for (m, _) in minas.iter_mut().zip(&celdas).filter(|(_, c)| es_mina(c)) {
+                *m += 1.0;
+            }
+            true
+        })?;
+        Ok(Frontera {
+            celdas,
+            soluciones,
+            minas_por_celda,
+        })
+    }
+
+    /// Retorna los indices de las celdas de la frontera.
+    pub(crate) fn celdas(&self) -> &[usize] {
+        &self.celdas
+    }
+
+    /// Retorna la cantidad de distribuciones válidas para cada cantidad de minas.
+    pub(crate) fn soluciones(&self) -> &[f64] {
+        &self.soluciones
+    }
+
+    /// Retorna, para cada celda de la frontera, la cantidad de distribuciones válidas con exactamente `k` minas en las que la celda es una mina, o `None` si no hay ninguna distribución válida con `k` minas.
+    pub(crate) fn minas_por_celda(&self, k: usize) -> Option<&[f64]> {
+        self.minas_por_celda[k].as_deref()
+    }
+
+    /// Recorre, a partir de la celda `inicio`, todas las celdas alcanzables a través de las restricciones y las retorna en el orden en que fueron visitadas.
+    fn recorrer_grupo(
+        inicio: usize,
+        restricciones: &[Restriccion],
+        por_celda: &[Vec<usize>],
+        visitadas: &mut [bool],
+    ) -> Vec<usize> {
+        let mut grupo = vec![inicio];
+        visitadas[inicio] = true;
+        let mut i = 0;
+        while let Some(&celda) = grupo.get(i) {
+            for &r in &por_celda[celda] {
+                for &vecina in restricciones[r].celdas() {
+                    if !visitadas[vecina] && !por_celda[vecina].is_empty() {
+                        visitadas[vecina] = true;
+                        grupo.push(vecina);
+                    }
+                }
+            }
+            i += 1;
+        }
+        grupo
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tablero::enumerador::PASOS_MAXIMOS;
+
+    #[test]
+    fn separar_fronteras_independientes() {
+        let restricciones = [
+            Restriccion::new(vec![0, 1], 1),
+            Restriccion::new(vec![1, 2], 1),
+            Restriccion::new(vec![4, 5], 1),
+        ];
+        let conocidas = vec![None; 6];
+        let resultado = Frontera::separar(&restricciones, &conocidas);
+        assert_eq!(resultado, vec![vec![0, 1, 2], vec![4, 5]]);
+    }
+
+    #[test]
+    fn separar_ignora_celdas_conocidas() {
+        let restricciones = [Restriccion::new(vec![0, 1, 2], 1)];
+        let conocidas = vec![None, Some(false), None];
+        let resultado = Frontera::separar(&restricciones, &conocidas);
+        assert_eq!(resultado, vec![vec![0, 2]]);
+    }
+
+    #[test]
+    fn enumerar_frontera() {
+        let restricciones = [
+            Restriccion::new(vec![0, 1], 1),
+            Restriccion::new(vec![1, 2], 1),
+        ];
+        let mut conocidas = vec![None; 3];
+        let mut pasos = PASOS_MAXIMOS;
+        let frontera =
+            Frontera::enumerar(vec![0, 1, 2], &restricciones, &mut conocidas, &mut pasos).unwrap();
+        // Soluciones: {1} y {0, 2}
+        assert_eq!(frontera.soluciones(), &[0.0, 1.0, 1.0, 0.0]);
+        assert_eq!(frontera.minas_por_celda(0), None);
+        assert_eq!(frontera.minas_por_celda(1), Some(&[0.0, 1.0, 0.0][..]));
+        assert_eq!(frontera.minas_por_celda(2), Some(&[1.0, 0.0, 1.0][..]));
+        assert_eq!(conocidas, vec![None; 3]);
+    }
+}
@@ -0,0 +1,501 @@
+//! # Generador
+//! `generador` es un submódulo que contiene a [`Generador`], encargado de construir [`Tablero`] aleatorios y reproducibles a partir de una semilla.
+//!
+//! [`Tablero`]: ../struct.Tablero.html
+
+use crate::error::error_mapa::ErrorMapa;
+use crate::tablero::aleatorio::Aleatorio;
+use crate::tablero::casillero::Casillero;
+use crate::tablero::coordenada::Coordenadas2D;
+use crate::tablero::estado_partida::EstadoPartida;
+use crate::tablero::partida::Partida;
+use crate::tablero::simbolos::Simbolos;
+use crate::tablero::topologia::Topologia;
+use crate::tablero::vecindario::Vecindario;
+use crate::tablero::Tablero;
+
+/// Constante que indica la cantidad máxima de tableros candidatos que se generan al buscar uno que pueda resolverse sin adivinar.
+pub const INTENTOS_MAXIMOS: usize = 1000;
+
+#[derive(Debug, PartialEq, Eq)]
+/// Estructura que contiene la configuración necesaria para generar un [`Tablero`] aleatorio.
+///
+/// Dos generadores construidos con las mismas dimensiones y la misma semilla generan siempre el mismo [`Tablero`].
+pub struct Generador {
+    /// Cantidad de columnas del tablero a generar.
+    ancho: usize,
+    /// Cantidad de filas del tablero a generar.
+    largo: usize,
+    /// Semilla del [generador de números pseudoaleatorios][Aleatorio] usado para ubicar las minas.
+    semilla: u64,
+    /// [`Topologia`] de los tableros a generar. Por defecto es [acotada][Topologia::Acotada].
+    topologia: Topologia,
+    /// [`Vecindario`] de los tableros a generar. Por defecto es el de [Moore][Vecindario::Moore].
+    vecindario: Vecindario,
+}
+
+impl Generador {
+    /// Construye un nuevo [`Generador`] de tableros de `ancho` x `largo` celdas, cuyas minas se ubican según la `semilla`.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::generador::Generador;
+    /// #
+    /// # fn main() {
+    /// let generador = Generador::new(8, 8, 2022);
+    /// assert!(generador.is_ok());
+    /// // Tablero sin celdas
+    /// let generador = Generador::new(0, 8, 2022);
+    /// assert!(generador.is_err());
+    /// # }
+    /// ```
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`MapaVacio`][ErrorMapa::MapaVacio] si alguna de las dimensiones es `0`.
+    pub fn new(ancho: usize, largo: usize, semilla: u64) -> Result<Generador, ErrorMapa> {
+        match ancho == 0 || largo == 0 {
+            false => Ok(Generador {
+                ancho,
+                largo,
+                semilla,
+                topologia: Topologia::default(),
+                vecindario: Vecindario::default(),
+            }),
+            true => Err(ErrorMapa::MapaVacio),
+        }
+    }
+
+    /// Retorna el [`Generador`] configurado para generar tableros con la [`Topologia`] indicada.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::generador::Generador;
+    /// # use buscaminas::tablero::topologia::Topologia;
+    /// # use buscaminas::error::error_mapa::ErrorMapa;
+    /// #
+    /// # fn main() -> Result<(),ErrorMapa> {
+    /// let generador = Generador::new(9, 9, 7)?.con_topologia(Topologia::Toroidal);
+    /// let tablero = generador.generar_con_minas(10)?;
+    /// assert_eq!(tablero.topologia(), Topologia::Toroidal);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn con_topologia(self, topologia: Topologia) -> Generador {
+        Generador { topologia, ..self }
+    }
+
+    /// Retorna el [`Generador`] configurado para generar tableros con el [`Vecindario`] indicado.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::generador::Generador;
+    /// # use buscaminas::tablero::vecindario::Vecindario;
+    /// # use buscaminas::error::error_mapa::ErrorMapa;
+    /// #
+    /// # fn main() -> Result<(),ErrorMapa> {
+    /// let generador = Generador::new(9, 9, 7)?.con_vecindario(Vecindario::Hexagonal);
+    /// let tablero = generador.generar_con_minas(10)?;
+    /// assert_eq!(tablero.vecindario(), &Vecindario::Hexagonal);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn con_vecindario(self, vecindario: Vecindario) -> Generador {
+        Generador { vecindario, ..self }
+    }
+
+    /// Genera un [`Tablero`] **resuelto** con exactamente la cantidad de `minas` indicada.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::generador::Generador;
+    /// # use buscaminas::error::error_mapa::ErrorMapa;
+    /// #
+    /// # fn main() -> Result<(),ErrorMapa> {
+    /// let generador = Generador::new(9, 9, 7)?;
+    /// let tablero = generador.generar_con_minas(10)?;
+    /// assert_eq!(tablero, generador.generar_con_minas(10)?);
+    /// println!("{}", tablero);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`DemasiadasMinas`][ErrorMapa::DemasiadasMinas] si la cantidad de minas supera a la cantidad de celdas del tablero.
+    pub fn generar_con_minas(&self, minas: usize) -> Result<Tablero, ErrorMapa> {
+        self.generar(minas, &[], &mut Aleatorio::new(self.semilla))
+    }
+
+    /// Genera un [`Tablero`] **resuelto** con exactamente la cantidad de `minas` indicada, garantizando que la celda del `primer_click` no contenga una mina.
+    ///
+    /// Si `con_adyacentes` es `true`, tampoco habrá minas en las [celdas adyacentes](Vecindario::coordenadas_adyacentes) al primer click, por lo que este revelará una región sin minas adyacentes.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::generador::Generador;
+    /// # use buscaminas::tablero::coordenada::Coordenadas2D;
+    /// # use buscaminas::tablero::partida::Partida;
+    /// # use buscaminas::tablero::estado_partida::EstadoPartida;
+    /// # use buscaminas::error::Error;
+    /// #
+    /// # fn main() -> Result<(),Error> {
+    /// let primer_click = Coordenadas2D::new(4, 4);
+    /// let generador = Generador::new(9, 9, 7)?;
+    /// let tablero = generador.generar_con_primer_click(10, &primer_click, true)?;
+    /// let mut partida = Partida::new(tablero);
+    /// partida.revelar(primer_click)?;
+    /// assert_ne!(partida.estado(), EstadoPartida::Perdida);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errores
+    ///
+    /// - Retorna [`CeldaInexistente`][ErrorMapa::CeldaInexistente] si el primer click no pertenece al tablero.
+    /// - Retorna [`DemasiadasMinas`][ErrorMapa::DemasiadasMinas] si la cantidad de minas supera a la cantidad de celdas fuera de la zona segura.
+    pub fn generar_con_primer_click(
+        &self,
+        minas: usize,
+        primer_click: &Coordenadas2D,
+        con_adyacentes: bool,
+    ) -> Result<Tablero, ErrorMapa> {
+        let zona_segura = self.zona_segura(primer_click, con_adyacentes)?;
+        self.generar(minas, &zona_segura, &mut Aleatorio::new(self.semilla))
+    }
+
+    /// Genera un [`Tablero`] **resuelto** con exactamente la cantidad de `minas` indicada que puede resolverse por completo usando solo lógica, sin necesidad de adivinar, comenzando por el `primer_click`.
+    ///
+    /// El primer click y sus celdas adyacentes nunca contienen minas. Se generan tableros candidatos hasta encontrar uno en el que, revelando el primer click y luego únicamente las celdas que pueden [deducirse](crate::tablero::deduccion::Deduccion) como seguras, se gane la partida.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::generador::Generador;
+    /// # use buscaminas::tablero::coordenada::Coordenadas2D;
+    /// # use buscaminas::error::error_mapa::ErrorMapa;
+    /// #
+    /// # fn main() -> Result<(),ErrorMapa> {
+    /// let primer_click = Coordenadas2D::new(0, 0);
+    /// let generador = Generador::new(9, 9, 2022)?;
+    /// let tablero = generador.generar_sin_adivinar(10, &primer_click)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errores
+    ///
+    /// - Retorna los mismos errores que el [método] que genera tableros con el primer click seguro.
+    /// - Retorna [`GeneracionFallida`][ErrorMapa::GeneracionFallida] si luego de [`INTENTOS_MAXIMOS`] candidatos no se encontró ningún tablero que pueda resolverse sin adivinar.
+    ///
+    /// [método]: ./struct.Generador.html#method.generar_con_primer_click
+    pub fn generar_sin_adivinar(
+        &self,
+        minas: usize,
+        primer_click: &Coordenadas2D,
+    ) -> Result<Tablero, ErrorMapa> {
+        let zona_segura = self.zona_segura(primer_click, true)?;
+        let mut aleatorio = Aleatorio::new(self.semilla);
+        for _ in 0..INTENTOS_MAXIMOS {
+            let tablero = self.generar(minas, &zona_segura, &mut aleatorio)?;
+            if Self::es_resoluble_sin_adivinar(&tablero, *primer_click) {
+                return Ok(tablero);
+            }
+        }
+        Err(ErrorMapa::GeneracionFallida)
+    }
+
+    /// Genera un [`Tablero`] **resuelto** cuya proporción de minas sobre el total de celdas es la `densidad` indicada, redondeando la cantidad de minas al entero más cercano.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::generador::Generador;
+    /// # use buscaminas::error::error_mapa::ErrorMapa;
+    /// #
+    /// # fn main() -> Result<(),ErrorMapa> {
+    /// let generador = Generador::new(10, 10, 7)?;
+    /// // Tablero con 15 minas
+    /// let tablero = generador.generar_con_densidad(0.15)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`DensidadInvalida`][ErrorMapa::DensidadInvalida] si la densidad no pertenece al intervalo `[0, 1]`.
+    pub fn generar_con_densidad(&self, densidad: f64) -> Result<Tablero, ErrorMapa> {
+        if !(0.0..=1.0).contains(&densidad) {
+            return Err(ErrorMapa::DensidadInvalida);
+        }
+        let minas = (densidad * (self.ancho * self.largo) as f64).round() as usize;
+        self.generar_con_minas(minas)
+    }
+
+    /// Genera un [`Tablero`] **resuelto** con la cantidad de `minas` indicada, ubicadas en cualquier celda salvo las de indices `excluidas`.
+    ///
+    /// # Errores
+    ///
+    /// - Retorna [`MapaMalformado`][ErrorMapa::MapaMalformado] si el [`Vecindario`] no puede [utilizarse][Vecindario::verificar] con la [`Topologia`] y las dimensiones del tablero.
+    /// - Retorna [`DemasiadasMinas`][ErrorMapa::DemasiadasMinas] si la cantidad de minas supera a la cantidad de celdas disponibles.
+    fn generar(
+        &self,
+        minas: usize,
+        excluidas: &[usize],
+        aleatorio: &mut Aleatorio,
+    ) -> Result<Tablero, ErrorMapa> {
+        self.vecindario
+            .verificar(self.topologia, [self.ancho, self.largo])?;
+        let celdas = self.ancho * self.largo;
+        let candidatos: Vec<usize> = (0..celdas).filter(|i| !excluidas.contains(i)).collect();
+        if minas > candidatos.len() {
+            return Err(ErrorMapa::DemasiadasMinas(minas));
+        }
+        let mut mapa = vec![Casillero::Espacio(0); celdas];
+        for indice in Self::sortear(candidatos, minas, aleatorio) {
+            mapa[indice] = Casillero::Mina(1);
+        }
+        Tablero {
+            dimensiones: [self.ancho, self.largo],
+            topologia: self.topologia,
+            vecindario: self.vecindario.clone(),
+            simbolos: Simbolos::default(),
+            mapa,
+        }
+        .resolver()
+    }
+
+    /// Retorna los indices de las celdas en las que no se pueden ubicar minas para que el `primer_click` sea seguro.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`CeldaInexistente`][ErrorMapa::CeldaInexistente] si el primer click no pertenece al tablero.
+    fn zona_segura(
+        &self,
+        primer_click: &Coordenadas2D,
+        con_adyacentes: bool,
+    ) -> Result<Vec<usize>, ErrorMapa> {
+        if primer_click.x() >= self.ancho || primer_click.y() >= self.largo {
+            return Err(ErrorMapa::CeldaInexistente);
+        }
+        let mut zona = vec![*primer_click];
+        if con_adyacentes {
+            zona.extend(self.vecindario.coordenadas_adyacentes(
+                primer_click,
+                [self.ancho, self.largo],
+                self.topologia,
+            ));
+        }
+        Ok(zona.iter().map(|c| c.y() * self.ancho + c.x()).collect())
+    }
+
+    /// Simula una partida sobre el `tablero` en la que se revela el `primer_click` y luego solo las celdas deducidas como seguras. Retorna `true` si de esta forma se gana la partida.
+    fn es_resoluble_sin_adivinar(tablero: &Tablero, primer_click: Coordenadas2D) -> bool {
+        let mut partida = Partida::new(tablero.clone());
+        if partida.revelar(primer_click).is_err() {
+            return false;
+        }
+        while partida.estado() == EstadoPartida::EnCurso {
+            let deduccion = match partida.vista().map(|vista| vista.deducir()) {
+                Ok(Ok(deduccion)) if !deduccion.seguras().is_empty() => deduccion,
+                _ => return false,
+            };
+            for coordenada in deduccion.seguras() {
+                if partida.revelar(*coordenada).is_err() {
+                    break;
+                }
+            }
+        }
+        partida.estado() == EstadoPartida::Ganada
+    }
+
+    /// Elige al azar `cantidad` elementos distintos de los `candidatos`, mediante una mezcla parcial de *Fisher-Yates*.
+    fn sortear(
+        mut candidatos: Vec<usize>,
+        cantidad: usize,
+        aleatorio: &mut Aleatorio,
+    ) -> Vec<usize> {
+        for i in 0..cantidad {
+            let j = i + aleatorio.rango(candidatos.len() - i);
+            candidatos.swap(i, j);
+        }
+        candidatos.truncate(cantidad);
+        candidatos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tablero::builder::TableroBuilder;
+
+    fn contar_minas(tablero: &Tablero) -> usize {
+        tablero
+            .mapa
+            .iter()
+            .filter(|c| **c == Casillero::Mina(1))
+            .count()
+    }
+
+    #[test]
+    fn crear_generador() {
+        let resultado = Generador::new(3, 4, 5).unwrap();
+        let esperado = Generador {
+            ancho: 3,
+            largo: 4,
+            semilla: 5,
+            topologia: Topologia::Acotada,
+            vecindario: Vecindario::Moore,
+        };
+        assert_eq!(resultado, esperado);
+    }
+
+    #[test]
+    fn crear_generador_sin_celdas() {
+        assert_eq!(Generador::new(0, 4, 5), Err(ErrorMapa::MapaVacio));
+        assert_eq!(Generador::new(3, 0, 5), Err(ErrorMapa::MapaVacio));
+    }
+
+    #[test]
+    fn generar_con_cantidad_exacta_de_minas() {
+        let generador = Generador::new(16, 16, 40).unwrap();
+        for minas in [0, 1, 40, 255, 256] {
+            let tablero = generador.generar_con_minas(minas).unwrap();
+            assert_eq!(contar_minas(&tablero), minas);
+            assert_eq!(tablero.mapa.len(), 256);
+        }
+    }
+
+    #[test]
+    fn generar_es_deterministico_segun_la_semilla() {
+        let a = Generador::new(10, 8, 1).unwrap().generar_con_minas(20);
+        let b = Generador::new(10, 8, 1).unwrap().generar_con_minas(20);
+        let c = Generador::new(10, 8, 2).unwrap().generar_con_minas(20);
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn generar_tablero_resuelto() {
+        let tablero = Generador::new(5, 5, 3)
+            .unwrap()
+            .generar_con_minas(5)
+            .unwrap();
+        assert_eq!(tablero.resolver().unwrap(), tablero);
+    }
+
+    #[test]
+    fn generar_con_demasiadas_minas() {
+        let generador = Generador::new(2, 2, 0).unwrap();
+        let resultado = generador.generar_con_minas(5);
+        assert_eq!(resultado, Err(ErrorMapa::DemasiadasMinas(5)));
+    }
+
+    #[test]
+    fn generar_con_primer_click_seguro() {
+        let primer_click = Coordenadas2D::new(0, 0);
+        for semilla in 0..50 {
+            let tablero = Generador::new(3, 3, semilla)
+                .unwrap()
+                .generar_con_primer_click(8, &primer_click, false)
+                .unwrap();
+            assert_eq!(contar_minas(&tablero), 8);
+            assert_eq!(tablero.mapa[0], Casillero::Espacio(3));
+        }
+    }
+
+    #[test]
+    fn generar_con_primer_click_y_adyacentes_seguros() {
+        let primer_click = Coordenadas2D::new(2, 1);
+        for semilla in 0..50 {
+            let tablero = Generador::new(5, 4, semilla)
+                .unwrap()
+                .generar_con_primer_click(11, &primer_click, true)
+                .unwrap();
+            assert_eq!(contar_minas(&tablero), 11);
+            assert_eq!(tablero.mapa[7], Casillero::Espacio(0));
+        }
+    }
+
+    #[test]
+    fn generar_con_primer_click_sin_lugar_para_las_minas() {
+        let generador = Generador::new(3, 3, 0).unwrap();
+        let primer_click = Coordenadas2D::new(1, 1);
+        let resultado = generador.generar_con_primer_click(1, &primer_click, true);
+        assert_eq!(resultado, Err(ErrorMapa::DemasiadasMinas(1)));
+        let resultado = generador.generar_con_primer_click(9, &primer_click, false);
+        assert_eq!(resultado, Err(ErrorMapa::DemasiadasMinas(9)));
+    }
+
+    #[test]
+    fn generar_con_primer_click_fuera_del_tablero() {
+        let generador = Generador::new(3, 3, 0).unwrap();
+        let primer_click = Coordenadas2D::new(3, 0);
+        let resultado = generador.generar_con_primer_click(1, &primer_click, false);
+        assert_eq!(resultado, Err(ErrorMapa::CeldaInexistente));
+    }
+
+    #[test]
+    fn generar_sin_adivinar() {
+        let primer_click = Coordenadas2D::new(4, 4);
+        for semilla in 0..10 {
+            let tablero = Generador::new(9, 9, semilla)
+                .unwrap()
+                .generar_sin_adivinar(10, &primer_click)
+                .unwrap();
+            assert_eq!(contar_minas(&tablero), 10);
+            assert!(Generador::es_resoluble_sin_adivinar(&tablero, primer_click));
+        }
+    }
+
+    #[test]
+    fn detectar_tablero_que_requiere_adivinar() {
+        let tablero = TableroBuilder::new("...\n...\n*..\n")
+            .unwrap()
+            .crear_tablero()
+            .unwrap();
+        let resoluble = Generador::es_resoluble_sin_adivinar(&tablero, Coordenadas2D::new(2, 0));
+        assert!(resoluble);
+
+        // Dos celdas posibles para una única mina: no hay forma lógica de elegir.
+        let tablero = TableroBuilder::new("..\n..\n*.\n")
+            .unwrap()
+            .crear_tablero()
+            .unwrap();
+        let resoluble = Generador::es_resoluble_sin_adivinar(&tablero, Coordenadas2D::new(0, 0));
+        assert!(!resoluble);
+    }
+
+    #[test]
+    fn generar_sin_adivinar_imposible() {
+        let generador = Generador::new(4, 4, 0).unwrap();
+        let primer_click = Coordenadas2D::new(0, 0);
+        let resultado = generador.generar_sin_adivinar(13, &primer_click);
+        assert_eq!(resultado, Err(ErrorMapa::DemasiadasMinas(13)));
+    }
+
+    #[test]
+    fn generar_con_densidad() {
+        let generador = Generador::new(10, 10, 0).unwrap();
+        let tablero = generador.generar_con_densidad(0.15).unwrap();
+        assert_eq!(contar_minas(&tablero), 15);
+
+        let resultado = generador.generar_con_densidad(1.5);
+        assert_eq!(resultado, Err(ErrorMapa::DensidadInvalida));
+        let resultado = generador.generar_con_densidad(-0.1);
+        assert_eq!(resultado, Err(ErrorMapa::DensidadInvalida));
+    }
+
+    #[test]
+    fn generar_hexagonal_toroidal_con_filas_impares() {
+        let generador = Generador::new(4, 3, 0)
+            .unwrap()
+            .con_vecindario(Vecindario::Hexagonal)
+            .con_topologia(Topologia::Toroidal);
+        let resultado = generador.generar_con_minas(2);
+        assert_eq!(resultado, Err(ErrorMapa::MapaMalformado));
+        let generador = Generador::new(4, 4, 0).unwrap();
+        let generador = generador
+            .con_vecindario(Vecindario::Hexagonal)
+            .con_topologia(Topologia::Toroidal);
+        assert!(generador.generar_con_minas(2).is_ok());
+    }
+}
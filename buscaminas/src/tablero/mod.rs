@@ -2,70 +2,240 @@
 //!
 //! `tablero` es el módulo que contiene todo lo relacionado al mapa del problema a resolver.
 use crate::error::error_mapa::ErrorMapa;
+use crate::hilos_disponibles;
 use crate::tablero::casillero::Casillero;
-use crate::tablero::coordenada::Coordenadas2D;
+use crate::tablero::coordenada::Coordenadas;
+use crate::tablero::estadisticas::Estadisticas;
+use crate::tablero::simbolos::Simbolos;
+use crate::tablero::topologia::Topologia;
+use crate::tablero::vecindario::Vecindario;
 
-use std::fmt;
+use std::{fmt, panic, thread};
 
+pub mod aleatorio;
 pub mod builder;
 pub mod casillero;
+pub mod celda_parcial;
 pub mod coordenada;
+pub mod deduccion;
+pub(crate) mod enumerador;
+pub mod estadisticas;
+pub mod estado_celda;
+pub mod estado_partida;
+pub mod flujo;
+pub(crate) mod frontera;
+pub mod generador;
+pub mod partida;
+pub mod probabilidades;
+pub mod reconstruccion;
+pub(crate) mod restriccion;
+pub mod simbolos;
+pub mod tablero_parcial;
+pub mod topologia;
+pub mod unicidad;
+pub mod vecindario;
 
-#[derive(Debug, PartialEq, Eq)]
+/// Cantidad mínima de celdas de cada banda de filas que se resuelve en un hilo propio. Los tableros más chicos se resuelven en el hilo actual, ya que crear los hilos costaría más que resolverlos.
+const CELDAS_POR_BANDA: usize = 1 << 16;
+
+/// Escribe las `celdas` de un tablero con las `dimensiones` y el `vecindario` dados, fila por fila. En tableros de tres dimensiones las capas se separan con una línea en blanco. Si alguna celda ocupa más de un caracter, todas se alinean a la derecha con ese ancho y se separan con un espacio.
+pub(crate) fn mostrar_celdas<const N: usize>(
+    f: &mut fmt::Formatter,
+    dimensiones: [usize; N],
+    vecindario: &Vecindario,
+    celdas: &[String],
+) -> fmt::Result {
+    let ancho = dimensiones.first().copied().unwrap_or(1);
+    let celdas_por_capa = ancho * dimensiones.get(1).copied().unwrap_or(1);
+    let ancho_celda = celdas.iter().map(|c| c.chars().count()).max().unwrap_or(1);
+    celdas.iter().enumerate().try_for_each(|(i, celda)| {
+        if i != 0 && i.is_multiple_of(celdas_por_capa) {
+            writeln!(f)?;
+        }
+        let separador = match vecindario.prefijo(i % celdas_por_capa, ancho) {
+            "" if ancho_celda > 1 && !i.is_multiple_of(ancho) => " ",
+            prefijo => prefijo,
+        };
+        write!(f, "{}{:>ancho_celda$}", separador, celda)?;
+        match i % ancho == ancho - 1 {
+            true => writeln!(f),
+            false => Ok(()),
+        }
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 /// Estructura que contiene toda la información necesaria para resolver y contener la solución del problema.
 ///
+/// El tablero puede tener cualquier cantidad `N` de dimensiones: una tira de celdas, el tablero clásico de dos dimensiones (el valor por defecto) o un tablero de tres dimensiones formado por capas. El recuento de minas y la expansión de regiones de una [partida][partida::Partida] son los mismos en todos los casos.
+///
 /// ### Observacion
 ///
 /// - El Tablero solo puede contener mapas que sea *rectangulares*.
-pub struct Tablero {
-    /// Campo utilizado para indicar el largo del tablero.
-    largo: usize,
-    /// Campo utilizado para indicar el ancho del tablero.
-    ancho: usize,
+/// - Como en tres dimensiones una celda puede tener más de nueve minas adyacentes, al mostrar un tablero con alguna cantidad de dos dígitos todas las celdas se alinean a ese ancho y se separan con un espacio.
+pub struct Tablero<const N: usize = 2> {
+    /// Campo utilizado para indicar la cantidad de celdas en cada dimensión del tablero: el ancho, el largo y la cantidad de capas.
+    dimensiones: [usize; N],
+    /// Campo utilizado para indicar cómo se unen los bordes del tablero, lo que determina qué celdas son adyacentes.
+    topologia: Topologia,
+    /// Campo utilizado para indicar qué celdas son adyacentes entre sí.
+    vecindario: Vecindario,
+    /// Campo utilizado para indicar los caracteres con los que se muestra cada celda.
+    simbolos: Simbolos,
     /// Campo que contiene la composicion del mapa. Este campo es utilizado tanto para almacenar el problema inicial, como la solucion del problema. Cada celda del tablero es representado por un [`Casillero`].
     mapa: Vec<Casillero>,
 }
 
-impl fmt::Display for Tablero {
+impl<const N: usize> fmt::Display for Tablero<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.mapa
+        let celdas: Vec<String> = self
+            .mapa
             .iter()
-            .enumerate()
-            .try_for_each(|(i, c)| match i % self.ancho == self.ancho - 1 {
-                true => writeln!(f, "{}", c),
-                false => write!(f, "{}", c),
-            })
+            .map(|c| self.simbolos.representar(c))
+            .collect();
+        mostrar_celdas(f, self.dimensiones, &self.vecindario, &celdas)
     }
 }
 
-impl Tablero {
+impl<const N: usize> Tablero<N> {
     /// Retorna un nuevo [`Tablero`] con la solucion del problema almacenado en el invocador.
     ///
+    /// Los tableros grandes se reparten en bandas de filas que se resuelven en paralelo, una por cada núcleo disponible, con el mismo resultado que en un único hilo.
+    ///
     /// # Errores
     ///
     /// Retornara el error dado por el [método] que calcula la cantidad de minas adyacentes.
     ///
     /// [método]: ./struct.Tablero.html#method.calcular_minas_adyacentes
     ///
-    fn resolver(&self) -> Result<Tablero, ErrorMapa> {
-        let mut solucion = Vec::with_capacity(self.ancho * self.largo);
-        for (index, _c) in self.mapa.iter().enumerate() {
-            match _c {
-                Casillero::Espacio(_) => {
-                    solucion.push(Casillero::Espacio(self.calcular_minas_adyacentes(index)?))
-                }
-                Casillero::Mina => solucion.push(Casillero::Mina),
-                _ => (),
-            }
-        }
+    fn resolver(&self) -> Result<Tablero<N>, ErrorMapa> {
+        let bandas = hilos_disponibles().min(self.mapa.len() / CELDAS_POR_BANDA);
+        let solucion = match bandas {
+            0 | 1 => self.resolver_banda(0, &self.mapa)?,
+            bandas => self.resolver_en_bandas(bandas)?,
+        };
         Ok(Tablero {
-            ancho: self.ancho,
-            largo: self.largo,
+            dimensiones: self.dimensiones,
+            topologia: self.topologia,
+            vecindario: self.vecindario.clone(),
+            simbolos: self.simbolos.clone(),
             mapa: solucion,
         })
     }
 
-    /// Retorna la cantidad de [minas][Mina] adyacentes a una celda determinada. En caso de que la coodenada corresponda a una [`Mina`][Mina], retornará `0`. Para ver la numeracion de las celdas dentro de [mapa] leer el siguiente [método].
+    /// Resuelve el mapa repartido en `bandas` de filas consecutivas, cada una en un hilo propio. Cada celda solo depende de sus adyacentes, que se leen del problema sin modificar, por lo que las bandas son independientes y se unen en orden.
+    fn resolver_en_bandas(&self, bandas: usize) -> Result<Vec<Casillero>, ErrorMapa> {
+        let ancho = self.dimensiones.first().copied().unwrap_or(1).max(1);
+        let celdas = (self.mapa.len() / ancho).div_ceil(bandas).max(1) * ancho;
+        let soluciones = thread::scope(|s| {
+            let hilos: Vec<_> = self
+                .mapa
+                .chunks(celdas)
+                .enumerate()
+                .map(|(i, banda)| s.spawn(move || self.resolver_banda(i * celdas, banda)))
+                .collect();
+            hilos
+                .into_iter()
+                .map(|hilo| hilo.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+        Ok(soluciones.concat())
+    }
+
+    /// Retorna la solución de la `banda` de celdas del mapa que comienza en el índice `inicio`.
+    fn resolver_banda(
+        &self,
+        inicio: usize,
+        banda: &[Casillero],
+    ) -> Result<Vec<Casillero>, ErrorMapa> {
+        banda
+            .iter()
+            .enumerate()
+            .map(|(i, casillero)| match casillero {
+                Casillero::Espacio(_) => Ok(Casillero::Espacio(
+                    self.calcular_minas_adyacentes(inicio + i)?,
+                )),
+                otro => Ok(*otro),
+            })
+            .collect()
+    }
+
+    /// Retorna la cantidad de celdas del tablero en cada una de sus dimensiones.
+    pub fn dimensiones(&self) -> [usize; N] {
+        self.dimensiones
+    }
+
+    /// Retorna la [`Topologia`] del tablero.
+    pub fn topologia(&self) -> Topologia {
+        self.topologia
+    }
+
+    /// Retorna el [`Vecindario`] del tablero.
+    pub fn vecindario(&self) -> &Vecindario {
+        &self.vecindario
+    }
+
+    /// Retorna el problema correspondiente al tablero: el mismo tablero con los espacios sin el recuento de minas adyacentes, tal como se lee antes de resolverlo.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::builder::TableroBuilder;
+    /// # use buscaminas::error::error_mapa::ErrorMapa;
+    /// #
+    /// # fn main() -> Result<(),ErrorMapa> {
+    /// let tablero = TableroBuilder::new("*..\n")?.crear_tablero()?;
+    /// assert_eq!(tablero.problema().to_string(), "*..\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn problema(&self) -> Tablero<N> {
+        let mapa = self
+            .mapa
+            .iter()
+            .map(|c| match c {
+                Casillero::Espacio(_) => Casillero::Espacio(0),
+                otro => *otro,
+            })
+            .collect();
+        Tablero {
+            dimensiones: self.dimensiones,
+            topologia: self.topologia,
+            vecindario: self.vecindario.clone(),
+            simbolos: self.simbolos.clone(),
+            mapa,
+        }
+    }
+
+    /// Retorna las [`Estadisticas`] del tablero: la cantidad de celdas de cada tipo y la densidad de minas.
+    pub fn estadisticas(&self) -> Estadisticas {
+        Estadisticas::calcular(&self.mapa)
+    }
+
+    /// Retorna los [`Simbolos`] con los que se muestra el tablero.
+    pub fn simbolos(&self) -> &Simbolos {
+        &self.simbolos
+    }
+
+    /// Retorna el tablero configurado para mostrarse con los [`Simbolos`] indicados, que pueden ser distintos a los utilizados para leerlo.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::builder::TableroBuilder;
+    /// # use buscaminas::tablero::simbolos::Simbolos;
+    /// # use buscaminas::error::error_mapa::ErrorMapa;
+    /// #
+    /// # fn main() -> Result<(),ErrorMapa> {
+    /// let tablero = TableroBuilder::new("*..\n")?.crear_tablero()?;
+    /// let simbolos = Simbolos { mina: 'X', espacio: '0', ..Simbolos::default() };
+    /// assert_eq!(tablero.con_simbolos(simbolos).to_string(), "X10\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn con_simbolos(self, simbolos: Simbolos) -> Tablero<N> {
+        Tablero { simbolos, ..self }
+    }
+
+    /// Retorna la cantidad de [minas][Mina] adyacentes a una celda determinada, sumando el peso de cada una de ellas y restando las [anti-minas][Casillero::AntiMina]. En caso de que la coodenada corresponda a una [`Mina`][Mina], retornará `0`. Para ver la numeracion de las celdas dentro de [mapa] leer el siguiente [método].
     ///
     /// [Mina]: Casillero::Mina
     /// [método]: ./struct.Tablero.html#method.obtener_indice
@@ -77,15 +247,11 @@ impl Tablero {
     ///
     /// [método]: ./struct.Tablero.html#method.obtener_adyacentes
     ///
-    fn calcular_minas_adyacentes(&self, num_casillero: usize) -> Result<u8, ErrorMapa> {
-        let (fila, columna) = (num_casillero / self.ancho, num_casillero % self.ancho);
-        let coordenada = Coordenadas2D::new(columna, fila);
+    fn calcular_minas_adyacentes(&self, num_casillero: usize) -> Result<i32, ErrorMapa> {
+        let coordenada = self.obtener_coordenada(num_casillero);
 
         let adyacentes = self.obtener_adyacentes(coordenada)?;
-        Ok(adyacentes
-            .iter()
-            .filter(|&c| (**c) == Casillero::Mina)
-            .count() as u8)
+        Ok(adyacentes.iter().map(|c| c.minas()).sum())
     }
 
     /// Retorna la cantidad de minas adyacentes a un [`Espacio`], en caso de ser una [`Mina`] se retornará un [vector][Vec] vacío.
@@ -98,12 +264,11 @@ impl Tablero {
     /// [`Espacio`]: Casillero::Espacio
     /// [método]: ./struct.Tablero.html#method.obtener_casillero
     ///
-    fn obtener_adyacentes(&self, coordenada: Coordenadas2D) -> Result<Vec<&Casillero>, ErrorMapa> {
-        if let Casillero::Mina = self.obtener_casillero(&coordenada)? {
+    fn obtener_adyacentes(&self, coordenada: Coordenadas<N>) -> Result<Vec<&Casillero>, ErrorMapa> {
+        if let Casillero::Mina(_) = self.obtener_casillero(&coordenada)? {
             return Ok(Vec::with_capacity(0));
         }
-        coordenada
-            .coordenadas_adyacentes(self.ancho, self.largo)
+        self.coordenadas_adyacentes(&coordenada)
             .iter()
             .map(|c| self.obtener_casillero(c))
             .collect()
@@ -118,14 +283,14 @@ impl Tablero {
     /// [`CeldaInexistente`]: ErrorMapa::CeldaInexistente
     /// [mapa]: ./struct.Tablero.html#structfield.mapa
     /// [indice]: ./struct.Tablero.html#method.obtener_indice
-    /// [coordenada]: coordenada::Coordenadas2D
-    fn obtener_casillero(&self, coordenada: &Coordenadas2D) -> Result<&Casillero, ErrorMapa> {
+    /// [coordenada]: coordenada::Coordenadas
+    fn obtener_casillero(&self, coordenada: &Coordenadas<N>) -> Result<&Casillero, ErrorMapa> {
         self.mapa
             .get(self.obtener_indice(coordenada)?)
             .ok_or(ErrorMapa::CeldaInexistente)
     }
     /// Retorna el indice correspondiente a una coordenada dentro del campo [mapa].
-    /// La numeracion esta dada por como se almaceno el [mapa].
+    /// La numeracion esta dada por como se almaceno el [mapa]: fila por fila y, en tableros de tres dimensiones, capa por capa.
     ///
     /// [mapa]: ./struct.Tablero.html#structfield.mapa
     ///
@@ -137,23 +302,50 @@ impl Tablero {
     /// // 4 5 6
     ///
     /// ```
-    fn obtener_indice(&self, coordenada: &Coordenadas2D) -> Result<usize, ErrorMapa> {
-        let (x, y) = (coordenada.x(), coordenada.y());
-        match self.ancho < x && self.largo < y {
-            false => Ok((y + 1) * self.ancho - (self.ancho - x)),
-            true => Err(ErrorMapa::CeldaInexistente),
-        }
+    fn obtener_indice(&self, coordenada: &Coordenadas<N>) -> Result<usize, ErrorMapa> {
+        coordenada
+            .indice(self.dimensiones)
+            .ok_or(ErrorMapa::CeldaInexistente)
+    }
+
+    /// Retorna la [coordenada] correspondiente a un indice dentro del campo [mapa]. Es la operación inversa al [método] que obtiene el indice de una coordenada.
+    ///
+    /// [coordenada]: coordenada::Coordenadas
+    /// [mapa]: ./struct.Tablero.html#structfield.mapa
+    /// [método]: ./struct.Tablero.html#method.obtener_indice
+    fn obtener_coordenada(&self, indice: usize) -> Coordenadas<N> {
+        Coordenadas::desde_indice(indice, self.dimensiones)
+    }
+
+    /// Retorna los indices dentro del campo [mapa] de todas las celdas adyacentes a la celda del indice dado.
+    ///
+    /// [mapa]: ./struct.Tablero.html#structfield.mapa
+    fn indices_adyacentes(&self, indice: usize) -> Vec<usize> {
+        self.coordenadas_adyacentes(&self.obtener_coordenada(indice))
+            .iter()
+            .filter_map(|c| self.obtener_indice(c).ok())
+            .collect()
+    }
+
+    /// Retorna las [coordenadas][Coordenadas] adyacentes a la dada, según el [`Vecindario`] y la [`Topologia`] del tablero.
+    fn coordenadas_adyacentes(&self, coordenada: &Coordenadas<N>) -> Vec<Coordenadas<N>> {
+        self.vecindario
+            .coordenadas_adyacentes(coordenada, self.dimensiones, self.topologia)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tablero::builder::TableroBuilder;
+    use crate::tablero::coordenada::{Coordenadas2D, Coordenadas3D};
     #[test]
     fn calcular_minas_adyacentes_tablero_vacio() {
         let tablero = Tablero {
-            ancho: 2,
-            largo: 2,
+            dimensiones: [2, 2],
+            topologia: Topologia::Acotada,
+            vecindario: Vecindario::Moore,
+            simbolos: Simbolos::default(),
             mapa: vec![
                 Casillero::Espacio(0),
                 Casillero::Espacio(0),
@@ -170,13 +362,15 @@ mod tests {
     #[test]
     fn calcular_minas_adyacentes_tablero_lleno() {
         let tablero = Tablero {
-            ancho: 2,
-            largo: 2,
+            dimensiones: [2, 2],
+            topologia: Topologia::Acotada,
+            vecindario: Vecindario::Moore,
+            simbolos: Simbolos::default(),
             mapa: vec![
-                Casillero::Mina,
-                Casillero::Mina,
-                Casillero::Mina,
-                Casillero::Mina,
+                Casillero::Mina(1),
+                Casillero::Mina(1),
+                Casillero::Mina(1),
+                Casillero::Mina(1),
             ],
         };
         let esperado = 0;
@@ -189,15 +383,17 @@ mod tests {
     #[test]
     fn calcular_minas_adyacentes_tablero_mixto() {
         let tablero = Tablero {
-            ancho: 3,
-            largo: 2,
+            dimensiones: [3, 2],
+            topologia: Topologia::Acotada,
+            vecindario: Vecindario::Moore,
+            simbolos: Simbolos::default(),
             mapa: vec![
-                Casillero::Mina,
+                Casillero::Mina(1),
                 Casillero::Espacio(0),
-                Casillero::Mina,
+                Casillero::Mina(1),
                 Casillero::Espacio(0),
-                Casillero::Mina,
-                Casillero::Mina,
+                Casillero::Mina(1),
+                Casillero::Mina(1),
             ],
         };
         // *.* > *4*
@@ -215,8 +411,10 @@ mod tests {
     #[test]
     fn resolver_tablero_vacio() {
         let tablero = Tablero {
-            ancho: 2,
-            largo: 2,
+            dimensiones: [2, 2],
+            topologia: Topologia::Acotada,
+            vecindario: Vecindario::Moore,
+            simbolos: Simbolos::default(),
             mapa: vec![
                 Casillero::Espacio(0),
                 Casillero::Espacio(0),
@@ -231,13 +429,15 @@ mod tests {
     #[test]
     fn resolver_tablero_lleno() {
         let tablero = Tablero {
-            ancho: 2,
-            largo: 2,
+            dimensiones: [2, 2],
+            topologia: Topologia::Acotada,
+            vecindario: Vecindario::Moore,
+            simbolos: Simbolos::default(),
             mapa: vec![
-                Casillero::Mina,
-                Casillero::Mina,
-                Casillero::Mina,
-                Casillero::Mina,
+                Casillero::Mina(1),
+                Casillero::Mina(1),
+                Casillero::Mina(1),
+                Casillero::Mina(1),
             ],
         };
         let resultado = tablero.resolver().unwrap();
@@ -247,28 +447,32 @@ mod tests {
     #[test]
     fn resolver_tablero_mixto() {
         let tablero = Tablero {
-            ancho: 3,
-            largo: 2,
+            dimensiones: [3, 2],
+            topologia: Topologia::Acotada,
+            vecindario: Vecindario::Moore,
+            simbolos: Simbolos::default(),
             mapa: vec![
-                Casillero::Mina,
+                Casillero::Mina(1),
                 Casillero::Espacio(0),
-                Casillero::Mina,
+                Casillero::Mina(1),
                 Casillero::Espacio(0),
-                Casillero::Mina,
-                Casillero::Mina,
+                Casillero::Mina(1),
+                Casillero::Mina(1),
             ],
         };
         let resultado = tablero.resolver().unwrap();
         let esperado = Tablero {
-            ancho: 3,
-            largo: 2,
+            dimensiones: [3, 2],
+            topologia: Topologia::Acotada,
+            vecindario: Vecindario::Moore,
+            simbolos: Simbolos::default(),
             mapa: vec![
-                Casillero::Mina,
+                Casillero::Mina(1),
                 Casillero::Espacio(4),
-                Casillero::Mina,
+                Casillero::Mina(1),
                 Casillero::Espacio(2),
-                Casillero::Mina,
-                Casillero::Mina,
+                Casillero::Mina(1),
+                Casillero::Mina(1),
             ],
         };
         assert_eq!(resultado, esperado);
@@ -277,8 +481,10 @@ mod tests {
     #[test]
     fn transformar_coordenada_a_indice() {
         let tablero = Tablero {
-            ancho: 5,
-            largo: 5,
+            dimensiones: [5, 5],
+            topologia: Topologia::Acotada,
+            vecindario: Vecindario::Moore,
+            simbolos: Simbolos::default(),
             mapa: vec![],
         };
         let coordenada = Coordenadas2D::new(4, 3);
@@ -290,5 +496,180 @@ mod tests {
         let esperado = 19;
         let resultado = tablero.obtener_indice(&coordenada).unwrap();
         assert_eq!(resultado, esperado);
+        assert_eq!(tablero.obtener_coordenada(esperado), coordenada);
+    }
+
+    #[test]
+    fn obtener_indices_adyacentes() {
+        let tablero = Tablero {
+            dimensiones: [3, 2],
+            topologia: Topologia::Acotada,
+            vecindario: Vecindario::Moore,
+            simbolos: Simbolos::default(),
+            mapa: vec![],
+        };
+        // 0, 1, 2
+        // 3, 4, 5
+        assert_eq!(tablero.indices_adyacentes(0), vec![3, 1, 4]);
+        assert_eq!(tablero.indices_adyacentes(4), vec![0, 3, 1, 2, 5]);
+    }
+
+    #[test]
+    fn transformar_coordenada_fuera_del_tablero() {
+        let tablero = Tablero {
+            dimensiones: [5, 3],
+            topologia: Topologia::Acotada,
+            vecindario: Vecindario::Moore,
+            simbolos: Simbolos::default(),
+            mapa: vec![],
+        };
+        let resultado = tablero.obtener_indice(&Coordenadas2D::new(5, 0));
+        assert_eq!(resultado, Err(ErrorMapa::CeldaInexistente));
+
+        let resultado = tablero.obtener_indice(&Coordenadas2D::new(0, 3));
+        assert_eq!(resultado, Err(ErrorMapa::CeldaInexistente));
+    }
+
+    #[test]
+    fn calcular_minas_adyacentes_tablero_toroidal() {
+        let tablero = TableroBuilder::new("*...\n....\n....\n....\n")
+            .unwrap()
+            .con_topologia(Topologia::Toroidal)
+            .crear_tablero()
+            .unwrap();
+        assert_eq!(tablero.to_string(), "*1.1\n11.1\n....\n11.1\n");
+        assert_eq!(
+            tablero.indices_adyacentes(0),
+            vec![15, 3, 7, 12, 4, 13, 1, 5]
+        );
+    }
+
+    #[test]
+    fn calcular_minas_adyacentes_con_otros_vecindarios() {
+        let builder = TableroBuilder::new("...\n.*.\n...\n").unwrap();
+        let resultado = builder
+            .con_vecindario(Vecindario::VonNeumann)
+            .crear_tablero()
+            .unwrap();
+        assert_eq!(resultado.to_string(), ".1.\n1*1\n.1.\n");
+
+        let builder = TableroBuilder::new("*...\n....\n....\n").unwrap();
+        let resultado = builder.con_vecindario(Vecindario::Caballo).crear_tablero();
+        assert_eq!(resultado.unwrap().to_string(), "*...\n..1.\n.1..\n");
+
+        let builder = TableroBuilder::new("*...\n").unwrap();
+        let vecindario = Vecindario::Personalizado(vec![(-3, 0), (3, 0)]);
+        let resultado = builder.con_vecindario(vecindario).crear_tablero();
+        assert_eq!(resultado.unwrap().to_string(), "*..1\n");
+    }
+
+    #[test]
+    fn resolver_en_bandas_como_en_un_solo_hilo() {
+        let tablero = TableroBuilder::new("*..*.\n.*...\n...*.\n*...*\n.*.*.\n")
+            .unwrap()
+            .con_topologia(Topologia::Toroidal)
+            .crear_tablero()
+            .unwrap()
+            .problema();
+        let esperado = tablero.resolver_banda(0, &tablero.mapa).unwrap();
+        for bandas in 2..=5 {
+            assert_eq!(tablero.resolver_en_bandas(bandas).unwrap(), esperado);
+        }
+    }
+
+    #[test]
+    fn resolver_tablero_de_tres_dimensiones() {
+        let mut mapa = vec![Casillero::Espacio(0); 27];
+        mapa[13] = Casillero::Mina(1);
+        let tablero = Tablero {
+            dimensiones: [3, 3, 3],
+            topologia: Topologia::Acotada,
+            vecindario: Vecindario::Moore,
+            simbolos: Simbolos::default(),
+            mapa,
+        };
+        let resultado = tablero.resolver().unwrap();
+        for (i, casillero) in resultado.mapa.iter().enumerate() {
+            match i {
+                13 => assert_eq!(*casillero, Casillero::Mina(1)),
+                _ => assert_eq!(*casillero, Casillero::Espacio(1)),
+            }
+        }
+        let coordenada = Coordenadas3D::new(1, 1, 1);
+        assert_eq!(tablero.obtener_indice(&coordenada), Ok(13));
+        assert_eq!(tablero.obtener_coordenada(13), coordenada);
+    }
+
+    #[test]
+    fn mostrar_cantidades_de_dos_digitos() {
+        let mut mapa = vec![Casillero::Mina(1); 27];
+        mapa[13] = Casillero::Espacio(0);
+        mapa[0] = Casillero::Espacio(0);
+        let tablero = Tablero {
+            dimensiones: [3, 3, 3],
+            topologia: Topologia::Acotada,
+            vecindario: Vecindario::Moore,
+            simbolos: Simbolos::default(),
+            mapa,
+        };
+        let resultado = tablero.resolver().unwrap();
+        let esperado = " 6  *  *\n *  *  *\n *  *  *\n\n *  *  *\n * 25  *\n *  *  *\n\n *  *  *\n *  *  *\n *  *  *\n";
+        assert_eq!(resultado.to_string(), esperado);
+    }
+
+    #[test]
+    fn resolver_tira_de_celdas() {
+        let tablero = Tablero {
+            dimensiones: [4],
+            topologia: Topologia::Toroidal,
+            vecindario: Vecindario::Moore,
+            simbolos: Simbolos::default(),
+            mapa: vec![
+                Casillero::Mina(1),
+                Casillero::Espacio(0),
+                Casillero::Espacio(0),
+                Casillero::Espacio(0),
+            ],
+        };
+        assert_eq!(tablero.resolver().unwrap().to_string(), "*1.1\n");
+    }
+
+    #[test]
+    fn calcular_minas_adyacentes_con_peso() {
+        let builder = TableroBuilder::new("*@&\n...\n").unwrap();
+        let resultado = builder.crear_tablero().unwrap();
+        assert_eq!(resultado.to_string(), "*@&\n365\n");
+
+        let builder = TableroBuilder::new("&&&\n&.&\n&&&\n").unwrap();
+        let resultado = builder.crear_tablero().unwrap();
+        assert_eq!(resultado.to_string(), " &  &  &\n & 24  &\n &  &  &\n");
+    }
+
+    #[test]
+    fn calcular_minas_adyacentes_sin_desbordar() {
+        let fila = format!("{}\n", "&".repeat(7));
+        let mut casilleros = fila.repeat(7);
+        casilleros.replace_range(27..28, ".");
+        let desplazamientos = (-3..=3)
+            .flat_map(|dx| (-3..=3).map(move |dy| (dx, dy)))
+            .collect();
+        let builder = TableroBuilder::new(&casilleros).unwrap();
+        let vecindario = Vecindario::Personalizado(desplazamientos);
+        let resultado = builder.con_vecindario(vecindario).crear_tablero().unwrap();
+        assert_eq!(resultado.mapa[24], Casillero::Espacio(144));
+    }
+
+    #[test]
+    fn calcular_minas_adyacentes_con_anti_minas() {
+        let builder = TableroBuilder::new("*.~\n~.~\n").unwrap();
+        let resultado = builder.crear_tablero().unwrap();
+        assert_eq!(resultado.to_string(), " * -2  ~\n ~ -2  ~\n");
+    }
+
+    #[test]
+    fn calcular_minas_adyacentes_con_paredes() {
+        let builder = TableroBuilder::new("#*.\n.##\n").unwrap();
+        let resultado = builder.crear_tablero().unwrap();
+        assert_eq!(resultado.to_string(), "#*1\n1##\n");
     }
 }
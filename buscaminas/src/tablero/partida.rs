@@ -0,0 +1,522 @@
+//! # Partida
+//! `partida` es un submódulo que contiene la entidad [`Partida`], encargada de llevar el estado de un juego de buscaminas sobre un [`Tablero`] ya resuelto.
+//!
+//! [`Tablero`]: ../struct.Tablero.html
+
+use crate::error::error_partida::ErrorPartida;
+use crate::tablero::casillero::Casillero;
+use crate::tablero::celda_parcial::CeldaParcial;
+use crate::tablero::coordenada::Coordenadas;
+use crate::tablero::estado_celda::EstadoCelda;
+use crate::tablero::estado_partida::EstadoPartida;
+use crate::tablero::tablero_parcial::TableroParcial;
+use crate::tablero::{mostrar_celdas, Tablero};
+use std::fmt;
+
+/// Constante usada para representar a una celda que todavía no fue descubierta.
+pub const OCULTA_ICONO: u8 = b'?';
+/// Constante usada para representar a una celda marcada por el jugador.
+pub const MARCA_ICONO: u8 = b'F';
+
+#[derive(Debug, PartialEq, Eq)]
+/// Estructura que contiene el estado de un juego de buscaminas.
+///
+/// Envuelve a un [`Tablero`] resuelto, de cualquier cantidad de dimensiones, y registra para cada una de sus celdas si fue [revelada, marcada o sigue oculta][EstadoCelda].
+pub struct Partida<const N: usize = 2> {
+    /// [`Tablero`] resuelto sobre el que se juega la partida.
+    tablero: Tablero<N>,
+    /// Estado de cada celda del tablero, siguiendo la misma numeración que su mapa.
+    estados: Vec<EstadoCelda>,
+    /// Estado general de la partida.
+    estado: EstadoPartida,
+    /// Cantidad de [espacios][Casillero::Espacio] que todavía no fueron revelados. Cuando llega a `0` la partida se considera ganada.
+    espacios_ocultos: usize,
+}
+
+impl<const N: usize> fmt::Display for Partida<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let celdas: Vec<String> = self
+            .estados
+            .iter()
+            .zip(self.tablero.mapa.iter())
+            .map(|(estado, casillero)| match (estado, casillero) {
+                (EstadoCelda::Revelada, _) | (_, Casillero::Pared) => {
+                    self.tablero.simbolos.representar(casillero)
+                }
+                (EstadoCelda::Oculta, _) => self.tablero.simbolos.oculta.to_string(),
+                (EstadoCelda::Marcada, _) => self.tablero.simbolos.marca.to_string(),
+            })
+            .collect();
+        mostrar_celdas(
+            f,
+            self.tablero.dimensiones,
+            &self.tablero.vecindario,
+            &celdas,
+        )
+    }
+}
+
+impl<const N: usize> Partida<N> {
+    /// Construye una nueva [`Partida`] con todas las celdas del [`Tablero`] ocultas.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::builder::TableroBuilder;
+    /// # use buscaminas::tablero::partida::Partida;
+    /// # use buscaminas::tablero::estado_partida::EstadoPartida;
+    /// # use buscaminas::error::error_mapa::ErrorMapa;
+    /// #
+    /// # fn main() -> Result<(),ErrorMapa> {
+    /// let tablero = TableroBuilder::new("*.\n..\n")?.crear_tablero()?;
+    /// let partida = Partida::new(tablero);
+    /// assert_eq!(partida.estado(), EstadoPartida::EnCurso);
+    /// println!("{}", partida);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(tablero: Tablero<N>) -> Partida<N> {
+        let espacios_ocultos = tablero
+            .mapa
+            .iter()
+            .filter(|c| matches!(c, Casillero::Espacio(_)))
+            .count();
+        let estado = match espacios_ocultos {
+            0 => EstadoPartida::Ganada,
+            _ => EstadoPartida::EnCurso,
+        };
+        Partida {
+            estados: vec![EstadoCelda::Oculta; tablero.mapa.len()],
+            tablero,
+            estado,
+            espacios_ocultos,
+        }
+    }
+
+    /// Revela la celda correspondiente a la [coordenada][Coordenadas] y retorna las coordenadas de todas las celdas que fueron descubiertas en la jugada.
+    ///
+    /// Si la celda revelada es un [`Espacio`][Casillero::Espacio] sin minas adyacentes, se revela automáticamente toda la región conexa de espacios sin minas adyacentes junto con su borde numerado.
+    /// Revelar una [`Mina`][Casillero::Mina] o una [`AntiMina`][Casillero::AntiMina] hace que la partida se [pierda][EstadoPartida::Perdida], mientras que revelar el último [`Espacio`][Casillero::Espacio] oculto hace que se [gane][EstadoPartida::Ganada].
+    /// Las celdas ya reveladas, las [marcadas][EstadoCelda::Marcada] y las [paredes][Casillero::Pared] no se modifican, por lo que se retorna un vector vacío.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::builder::TableroBuilder;
+    /// # use buscaminas::tablero::coordenada::Coordenadas2D;
+    /// # use buscaminas::tablero::partida::Partida;
+    /// # use buscaminas::tablero::estado_partida::EstadoPartida;
+    /// # use buscaminas::error::Error;
+    /// #
+    /// # fn main() -> Result<(),Error> {
+    /// let tablero = TableroBuilder::new("*.\n")?.crear_tablero()?;
+    /// let mut partida = Partida::new(tablero);
+    /// let reveladas = partida.revelar(Coordenadas2D::new(1, 0))?;
+    /// assert_eq!(reveladas, vec![Coordenadas2D::new(1, 0)]);
+    /// assert_eq!(partida.estado(), EstadoPartida::Ganada);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errores
+    ///
+    /// - Retorna [`PartidaFinalizada`][ErrorPartida::PartidaFinalizada] si la partida ya fue ganada o perdida.
+    /// - Retorna [`CeldaInexistente`](crate::error::error_mapa::ErrorMapa::CeldaInexistente) si la coordenada no pertenece al tablero.
+    pub fn revelar(
+        &mut self,
+        coordenada: Coordenadas<N>,
+    ) -> Result<Vec<Coordenadas<N>>, ErrorPartida> {
+        self.verificar_en_curso()?;
+        let indice = self.tablero.obtener_indice(&coordenada)?;
+        if self.estados[indice] != EstadoCelda::Oculta || self.es_pared(indice) {
+            return Ok(Vec::with_capacity(0));
+        }
+        Ok(self.expandir(indice))
+    }
+
+    /// Alterna la marca de la celda correspondiente a la [coordenada][Coordenadas] y retorna su nuevo [estado][EstadoCelda]. Las celdas reveladas y las [paredes][Casillero::Pared] no pueden marcarse.
+    ///
+    /// # Errores
+    ///
+    /// Retorna los mismos errores que el [método] para revelar celdas.
+    ///
+    /// [método]: ./struct.Partida.html#method.revelar
+    pub fn marcar(&mut self, coordenada: Coordenadas<N>) -> Result<EstadoCelda, ErrorPartida> {
+        self.verificar_en_curso()?;
+        let indice = self.tablero.obtener_indice(&coordenada)?;
+        if self.es_pared(indice) {
+            return Ok(self.estados[indice]);
+        }
+        self.estados[indice] = match self.estados[indice] {
+            EstadoCelda::Oculta => EstadoCelda::Marcada,
+            EstadoCelda::Marcada => EstadoCelda::Oculta,
+            EstadoCelda::Revelada => EstadoCelda::Revelada,
+        };
+        Ok(self.estados[indice])
+    }
+
+    /// Retorna el [estado][EstadoPartida] actual de la partida.
+    pub fn estado(&self) -> EstadoPartida {
+        self.estado
+    }
+
+    /// Retorna el [estado][EstadoCelda] de la celda correspondiente a la [coordenada][Coordenadas].
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`CeldaInexistente`](crate::error::error_mapa::ErrorMapa::CeldaInexistente) si la coordenada no pertenece al tablero.
+    pub fn estado_celda(&self, coordenada: &Coordenadas<N>) -> Result<EstadoCelda, ErrorPartida> {
+        let indice = self.tablero.obtener_indice(coordenada)?;
+        Ok(self.estados[indice])
+    }
+
+    /// Revela la celda del indice dado y, mientras las celdas descubiertas no tengan minas ni anti-minas adyacentes, continúa revelando sus celdas adyacentes ocultas.
+    ///
+    /// La expansión se realiza con una pila de celdas pendientes en lugar de recursión, para no desbordar el stack en tableros de gran tamaño.
+    fn expandir(&mut self, inicio: usize) -> Vec<Coordenadas<N>> {
+        let mut reveladas = Vec::new();
+        let mut pendientes = vec![inicio];
+        self.descubrir(inicio);
+        while let Some(indice) = pendientes.pop() {
+            reveladas.push(self.tablero.obtener_coordenada(indice));
+            if !self.es_region_vacia(indice) {
+                continue;
+            }
+            for adyacente in self.tablero.indices_adyacentes(indice) {
+                if self.estados[adyacente] == EstadoCelda::Oculta && !self.es_pared(adyacente) {
+                    self.descubrir(adyacente);
+                    pendientes.push(adyacente);
+                }
+            }
+        }
+        reveladas
+    }
+
+    /// Retorna `true` si la celda del indice dado es un [`Espacio`][Casillero::Espacio] sin minas ni anti-minas adyacentes. Un recuento de `0` no alcanza, ya que una mina y una anti-mina adyacentes se anulan entre sí.
+    fn es_region_vacia(&self, indice: usize) -> bool {
+        self.tablero.mapa[indice] == Casillero::Espacio(0)
+            && self
+                .tablero
+                .indices_adyacentes(indice)
+                .into_iter()
+                .all(|i| {
+                    !matches!(
+                        self.tablero.mapa[i],
+                        Casillero::Mina(_) | Casillero::AntiMina
+                    )
+                })
+    }
+
+    /// Retorna `true` si la celda del indice dado es una [pared][Casillero::Pared].
+    fn es_pared(&self, indice: usize) -> bool {
+        self.tablero.mapa[indice] == Casillero::Pared
+    }
+
+    /// Marca como revelada la celda del indice dado y actualiza el [estado][EstadoPartida] de la partida según su [`Casillero`]. Una partida [perdida][EstadoPartida::Perdida] no pasa a estar ganada.
+    fn descubrir(&mut self, indice: usize) {
+        self.estados[indice] = EstadoCelda::Revelada;
+        match self.tablero.mapa[indice] {
+            Casillero::Mina(_) | Casillero::AntiMina => self.estado = EstadoPartida::Perdida,
+            _ => {
+                self.espacios_ocultos -= 1;
+                if self.espacios_ocultos == 0 && self.estado == EstadoPartida::EnCurso {
+                    self.estado = EstadoPartida::Ganada;
+                }
+            }
+        }
+    }
+
+    /// Retorna [`PartidaFinalizada`][ErrorPartida::PartidaFinalizada] en caso de que la partida ya no este en curso.
+    fn verificar_en_curso(&self) -> Result<(), ErrorPartida> {
+        match self.estado {
+            EstadoPartida::EnCurso => Ok(()),
+            _ => Err(ErrorPartida::PartidaFinalizada),
+        }
+    }
+
+    /// Retorna el [`TableroParcial`] con la información que el jugador conoce del tablero. Las celdas marcadas se consideran desconocidas, ya que la marca no garantiza que contengan una mina. Los espacios adyacentes a alguna [anti-mina][Casillero::AntiMina] también se consideran desconocidos, ya que la deducción no contempla las anti-minas y su recuento no indica la cantidad de minas adyacentes.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`MinasConPeso`][ErrorPartida::MinasConPeso] si el tablero contiene celdas con varias minas, ya que la deducción supone que cada celda tiene a lo sumo una mina.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::builder::TableroBuilder;
+    /// # use buscaminas::tablero::coordenada::Coordenadas2D;
+    /// # use buscaminas::tablero::partida::Partida;
+    /// # use buscaminas::error::Error;
+    /// #
+    /// # fn main() -> Result<(),Error> {
+    /// let tablero = TableroBuilder::new("...\n...\n..*\n")?.crear_tablero()?;
+    /// let mut partida = Partida::new(tablero);
+    /// partida.revelar(Coordenadas2D::new(0, 0))?;
+    /// // Pista: la celda oculta restante es una mina.
+    /// let pista = partida.vista()?.deducir()?;
+    /// assert_eq!(pista.minas(), &[Coordenadas2D::new(2, 2)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn vista(&self) -> Result<TableroParcial<N>, ErrorPartida> {
+        if self.tablero.mapa.iter().any(|c| c.minas() > 1) {
+            return Err(ErrorPartida::MinasConPeso);
+        }
+        let celdas = (0..self.tablero.mapa.len())
+            .map(|indice| self.celda_visible(indice))
+            .collect();
+        Ok(TableroParcial::new(
+            self.tablero.dimensiones,
+            self.tablero.topologia,
+            self.tablero.vecindario.clone(),
+            self.tablero.simbolos.clone(),
+            celdas,
+        ))
+    }
+
+    /// Retorna lo que el jugador conoce de la celda del indice dado, según se describe en la [vista][Partida::vista].
+    fn celda_visible(&self, indice: usize) -> CeldaParcial {
+        let junto_a_anti_mina = || {
+            self.tablero
+                .indices_adyacentes(indice)
+                .into_iter()
+                .any(|i| self.tablero.mapa[i] == Casillero::AntiMina)
+        };
+        match (self.estados[indice], self.tablero.mapa[indice]) {
+            (_, Casillero::Pared) => CeldaParcial::Pared,
+            (EstadoCelda::Revelada, Casillero::Espacio(n)) if !junto_a_anti_mina() => {
+                u8::try_from(n).map_or(CeldaParcial::Desconocida, CeldaParcial::Descubierta)
+            }
+            (EstadoCelda::Revelada, Casillero::Mina(_)) => CeldaParcial::Mina,
+            _ => CeldaParcial::Desconocida,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::error_mapa::ErrorMapa;
+    use crate::tablero::builder::TableroBuilder;
+    use crate::tablero::coordenada::{Coordenadas1D, Coordenadas2D, Coordenadas3D};
+    use crate::tablero::simbolos::Simbolos;
+    use crate::tablero::topologia::Topologia;
+    use crate::tablero::vecindario::Vecindario;
+
+    fn crear_partida(casilleros: &str) -> Partida {
+        let tablero = TableroBuilder::new(casilleros)
+            .unwrap()
+            .crear_tablero()
+            .unwrap();
+        Partida::new(tablero)
+    }
+
+    #[test]
+    fn crear_partida_con_celdas_ocultas() {
+        let partida = crear_partida("*.\n..\n");
+        assert_eq!(partida.estado(), EstadoPartida::EnCurso);
+        assert_eq!(partida.espacios_ocultos, 3);
+        assert!(partida.estados.iter().all(|e| *e == EstadoCelda::Oculta));
+        assert_eq!(partida.to_string(), "??\n??\n");
+    }
+
+    #[test]
+    fn revelar_mina_pierde_la_partida() {
+        let mut partida = crear_partida("*.\n..\n");
+        let resultado = partida.revelar(Coordenadas2D::new(0, 0)).unwrap();
+        assert_eq!(resultado, vec![Coordenadas2D::new(0, 0)]);
+        assert_eq!(partida.estado(), EstadoPartida::Perdida);
+        assert_eq!(partida.to_string(), "*?\n??\n");
+    }
+
+    #[test]
+    fn revelar_todos_los_espacios_gana_la_partida() {
+        let mut partida = crear_partida("*.\n..\n");
+        for (x, y) in [(1, 0), (0, 1), (1, 1)] {
+            partida.revelar(Coordenadas2D::new(x, y)).unwrap();
+        }
+        assert_eq!(partida.estado(), EstadoPartida::Ganada);
+        assert_eq!(partida.to_string(), "?1\n11\n");
+    }
+
+    #[test]
+    fn revelar_celda_ya_revelada_o_marcada() {
+        let mut partida = crear_partida("*..\n...\n");
+        partida.revelar(Coordenadas2D::new(1, 0)).unwrap();
+        let resultado = partida.revelar(Coordenadas2D::new(1, 0)).unwrap();
+        assert!(resultado.is_empty());
+
+        partida.marcar(Coordenadas2D::new(0, 0)).unwrap();
+        let resultado = partida.revelar(Coordenadas2D::new(0, 0)).unwrap();
+        assert!(resultado.is_empty());
+        assert_eq!(partida.estado(), EstadoPartida::EnCurso);
+    }
+
+    #[test]
+    fn revelar_region_sin_minas_adyacentes() {
+        let mut partida = crear_partida("....\n....\n...*\n");
+        // ....    ....
+        // .... >  ..11
+        // ...*    ..1*
+        let resultado = partida.revelar(Coordenadas2D::new(0, 0)).unwrap();
+        assert_eq!(resultado.len(), 11);
+        assert!(!resultado.contains(&Coordenadas2D::new(3, 2)));
+        assert_eq!(partida.estado(), EstadoPartida::Ganada);
+        assert_eq!(partida.to_string(), "....\n..11\n..1?\n");
+    }
+
+    #[test]
+    fn revelar_region_respeta_marcas_y_bordes() {
+        let mut partida = crear_partida("...\n...\n***\n...\n");
+        partida.marcar(Coordenadas2D::new(2, 0)).unwrap();
+        let resultado = partida.revelar(Coordenadas2D::new(0, 0)).unwrap();
+        assert_eq!(resultado.len(), 5);
+        assert_eq!(partida.estado(), EstadoPartida::EnCurso);
+        assert_eq!(partida.to_string(), "..F\n232\n???\n???\n");
+    }
+
+    #[test]
+    fn revelar_region_en_tablero_grande() {
+        let ancho = 500;
+        let fila = format!("{}\n", ".".repeat(ancho));
+        let mut partida = crear_partida(&fila.repeat(ancho));
+        let resultado = partida.revelar(Coordenadas2D::new(0, 0)).unwrap();
+        assert_eq!(resultado.len(), ancho * ancho);
+        assert_eq!(partida.estado(), EstadoPartida::Ganada);
+    }
+
+    #[test]
+    fn vista_de_la_partida() {
+        let mut partida = crear_partida("*.\n..\n");
+        partida.marcar(Coordenadas2D::new(0, 0)).unwrap();
+        partida.revelar(Coordenadas2D::new(1, 1)).unwrap();
+        let esperado = TableroParcial::new(
+            [2, 2],
+            Topologia::Acotada,
+            Vecindario::Moore,
+            Simbolos::default(),
+            vec![
+                CeldaParcial::Desconocida,
+                CeldaParcial::Desconocida,
+                CeldaParcial::Desconocida,
+                CeldaParcial::Descubierta(1),
+            ],
+        );
+        assert_eq!(partida.vista(), Ok(esperado));
+    }
+
+    #[test]
+    fn vista_de_una_partida_con_minas_con_peso() {
+        let mut partida = crear_partida("@..\n...\n");
+        partida.revelar(Coordenadas2D::new(2, 0)).unwrap();
+        assert_eq!(partida.vista(), Err(ErrorPartida::MinasConPeso));
+    }
+
+    #[test]
+    fn vista_oculta_los_recuentos_junto_a_anti_minas() {
+        let mut partida = crear_partida("**~\n...\n...\n");
+        partida.revelar(Coordenadas2D::new(0, 2)).unwrap();
+        assert_eq!(partida.to_string(), "???\n21.\n...\n");
+        assert_eq!(partida.vista().unwrap().to_string(), "???\n2??\n...\n");
+    }
+
+    #[test]
+    fn marcar_y_desmarcar_celda() {
+        let mut partida = crear_partida("*.\n..\n");
+        let coordenada = Coordenadas2D::new(0, 0);
+        assert_eq!(partida.marcar(coordenada), Ok(EstadoCelda::Marcada));
+        assert_eq!(partida.to_string(), "F?\n??\n");
+        assert_eq!(partida.marcar(coordenada), Ok(EstadoCelda::Oculta));
+
+        partida.revelar(Coordenadas2D::new(1, 0)).unwrap();
+        let resultado = partida.marcar(Coordenadas2D::new(1, 0));
+        assert_eq!(resultado, Ok(EstadoCelda::Revelada));
+    }
+
+    #[test]
+    fn jugar_en_partida_finalizada() {
+        let mut partida = crear_partida("*.\n..\n");
+        partida.revelar(Coordenadas2D::new(0, 0)).unwrap();
+        let resultado = partida.revelar(Coordenadas2D::new(1, 0));
+        assert_eq!(resultado, Err(ErrorPartida::PartidaFinalizada));
+        let resultado = partida.marcar(Coordenadas2D::new(1, 0));
+        assert_eq!(resultado, Err(ErrorPartida::PartidaFinalizada));
+    }
+
+    #[test]
+    fn revelar_celda_inexistente() {
+        let mut partida = crear_partida("*.\n..\n");
+        let resultado = partida.revelar(Coordenadas2D::new(2, 0));
+        assert_eq!(
+            resultado,
+            Err(ErrorPartida::Mapa(ErrorMapa::CeldaInexistente))
+        );
+    }
+
+    #[test]
+    fn revelar_region_en_tablero_toroidal() {
+        let tablero = TableroBuilder::new("..*..\n").unwrap();
+        let mut partida = Partida::new(tablero.crear_tablero().unwrap());
+        let resultado = partida.revelar(Coordenadas2D::new(0, 0)).unwrap();
+        assert_eq!(resultado.len(), 2);
+
+        let tablero = tablero.con_topologia(Topologia::Toroidal);
+        let mut partida = Partida::new(tablero.crear_tablero().unwrap());
+        let resultado = partida.revelar(Coordenadas2D::new(0, 0)).unwrap();
+        assert_eq!(resultado.len(), 4);
+        assert_eq!(partida.estado(), EstadoPartida::Ganada);
+    }
+
+    #[test]
+    fn revelar_region_en_otras_dimensiones() {
+        let builder = TableroBuilder::new("...\n...\n\n...\n..*\n").unwrap();
+        let mut partida = Partida::new(builder.crear_tablero_3d().unwrap());
+        let resultado = partida.revelar(Coordenadas3D::new(0, 0, 0)).unwrap();
+        assert_eq!(resultado.len(), 8);
+        assert_eq!(partida.estado(), EstadoPartida::EnCurso);
+        assert_eq!(partida.to_string(), ".1?\n.1?\n\n.1?\n.1?\n");
+
+        let builder = TableroBuilder::new("..*...\n").unwrap();
+        let mut partida = Partida::new(builder.crear_tablero_1d().unwrap());
+        let resultado = partida.revelar(Coordenadas1D::new(5)).unwrap();
+        assert_eq!(resultado.len(), 3);
+        assert_eq!(partida.to_string(), "???1..\n");
+    }
+
+    #[test]
+    fn revelar_anti_mina_pierde_la_partida() {
+        let mut partida = crear_partida("~.\n..\n");
+        partida.revelar(Coordenadas2D::new(1, 1)).unwrap();
+        assert_eq!(partida.to_string(), " ?  ?\n ? -1\n");
+        partida.revelar(Coordenadas2D::new(0, 0)).unwrap();
+        assert_eq!(partida.estado(), EstadoPartida::Perdida);
+    }
+
+    #[test]
+    fn minas_y_anti_minas_que_se_anulan_detienen_la_expansion() {
+        let mut partida = crear_partida("*~.\n...\n");
+        let resultado = partida.revelar(Coordenadas2D::new(1, 1)).unwrap();
+        assert_eq!(resultado, vec![Coordenadas2D::new(1, 1)]);
+        assert_eq!(partida.estado(), EstadoPartida::EnCurso);
+        assert_eq!(partida.to_string(), "???\n?.?\n");
+    }
+
+    #[test]
+    fn una_partida_perdida_no_se_gana() {
+        let mut partida = crear_partida("*.\n");
+        partida.descubrir(0);
+        partida.descubrir(1);
+        assert_eq!(partida.estado(), EstadoPartida::Perdida);
+    }
+
+    #[test]
+    fn paredes_detienen_la_expansion() {
+        let mut partida = crear_partida("..#..\n..#..\n");
+        let resultado = partida.revelar(Coordenadas2D::new(0, 0)).unwrap();
+        assert_eq!(resultado.len(), 4);
+        assert_eq!(partida.estado(), EstadoPartida::EnCurso);
+        assert_eq!(partida.to_string(), "..#??\n..#??\n");
+
+        let pared = Coordenadas2D::new(2, 0);
+        assert!(partida.revelar(pared).unwrap().is_empty());
+        assert_eq!(partida.marcar(pared), Ok(EstadoCelda::Oculta));
+        assert_eq!(partida.vista().unwrap().to_string(), "..#??\n..#??\n");
+    }
+}
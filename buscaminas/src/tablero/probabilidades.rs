@@ -0,0 +1,303 @@
+//! # Probabilidades
+//! `probabilidades` es un submódulo que contiene a [`Probabilidades`], la probabilidad de que cada celda desconocida de un [`TableroParcial`] contenga una mina.
+//!
+//! [`TableroParcial`]: ../tablero_parcial/struct.TableroParcial.html
+use crate::error::error_mapa::ErrorMapa;
+use crate::tablero::coordenada::Coordenadas;
+use crate::tablero::enumerador::PASOS_MAXIMOS;
+use crate::tablero::frontera::Frontera;
+use crate::tablero::restriccion::Restriccion;
+use crate::tablero::tablero_parcial::TableroParcial;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Estructura que contiene, para cada celda desconocida de un [`TableroParcial`], la probabilidad exacta de que contenga una mina.
+///
+/// Todas las distribuciones de minas compatibles con los espacios descubiertos y con la cantidad total de minas se consideran igualmente probables.
+pub struct Probabilidades<const N: usize = 2> {
+    /// Campo utilizado para indicar la cantidad de celdas en cada dimensión del tablero.
+    dimensiones: [usize; N],
+    /// Probabilidad de cada celda, con la misma numeración que el tablero. Las celdas cuyo contenido ya se conoce no tienen probabilidad.
+    valores: Vec<Option<f64>>,
+}
+
+impl<const N: usize> Probabilidades<N> {
+    /// Calcula las [`Probabilidades`] de las celdas desconocidas del tablero `parcial`, sabiendo que en total contiene `minas_totales` minas.
+    ///
+    /// Las celdas adyacentes a espacios descubiertos se agrupan en [fronteras][Frontera] independientes, cuyas distribuciones de minas se enumeran por completo. El resto de las celdas desconocidas se pondera de forma combinatoria según la cantidad de minas que quedan para ellas.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`PistasInconsistentes`][ErrorMapa::PistasInconsistentes] si no existe ninguna distribución de minas compatible con el tablero y la cantidad total de minas, o [`BusquedaExcedida`][ErrorMapa::BusquedaExcedida] si las fronteras tienen demasiadas distribuciones para enumerarlas.
+    pub(crate) fn calcular(
+        parcial: &TableroParcial<N>,
+        minas_totales: usize,
+    ) -> Result<Probabilidades<N>, ErrorMapa> {
+        let mut conocidas = parcial.conocidas_iniciales();
+        let restricciones = parcial.restricciones();
+        let minas = Self::minas_restantes(&restricciones, &conocidas, minas_totales)?;
+        let mut pasos = PASOS_MAXIMOS;
+        let fronteras = Frontera::separar(&restricciones, &conocidas)
+            .into_iter()
+            .map(|celdas| Frontera::enumerar(celdas, &restricciones, &mut conocidas, &mut pasos))
+            .collect::<Result<Vec<Frontera>, ErrorMapa>>()?;
+        let interiores = Self::interiores(&conocidas, &fronteras);
+        let pesos = Self::pesos(interiores.len(), minas, conocidas.len());
+        let total = Self::convolucionar_todas(fronteras.iter());
+        let normalizacion: f64 = total.iter().zip(pesos.iter()).map(|(t, p)| t * p).sum();
+        if normalizacion == 0.0 {
+            return Err(ErrorMapa::PistasInconsistentes);
+        }
+        let mut valores = vec![None; conocidas.len()];
+        for (i, frontera) in fronteras.iter().enumerate() {
+            let otras = fronteras.iter().enumerate().filter(|(j, _)| *j != i);
+            let otras = Self::convolucionar_todas(otras.map(|(_, f)| f));
+            Self::asignar_frontera(frontera, &otras, &pesos, normalizacion, &mut valores);
+        }
+        let interior = Self::probabilidad_interior(&total, &pesos, minas, interiores.len());
+        for celda in interiores {
+            valores[celda] = Some(interior / normalizacion);
+        }
+        Ok(Probabilidades {
+            dimensiones: parcial.dimensiones(),
+            valores,
+        })
+    }
+
+    /// Retorna la probabilidad de que la celda correspondiente a la [coordenada][Coordenadas] contenga una mina, o `None` si la celda no es desconocida o no pertenece al tablero.
+    pub fn probabilidad(&self, coordenada: &Coordenadas<N>) -> Option<f64> {
+        *self.valores.get(coordenada.indice(self.dimensiones)?)?
+    }
+
+    /// Retorna la [coordenada][Coordenadas] de la celda desconocida con menor probabilidad de contener una mina, o `None` si no hay celdas desconocidas. En caso de empate se retorna la primera según la numeración del tablero.
+    pub fn mas_segura(&self) -> Option<Coordenadas<N>> {
+        let (indice, _) = self
+            .valores
+            .iter()
+            .enumerate()
+            .filter_map(|(i, v)| v.map(|v| (i, v)))
+            .fold(None, |mejor: Option<(usize, f64)>, (i, v)| match mejor {
+                Some((_, m)) if m <= v => mejor,
+                _ => Some((i, v)),
+            })?;
+        Some(Coordenadas::desde_indice(indice, self.dimensiones))
+    }
+
+    /// Retorna la cantidad de minas que quedan por ubicar entre las celdas desconocidas.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`PistasInconsistentes`][ErrorMapa::PistasInconsistentes] si hay más minas conocidas que `minas_totales` o si alguna restricción no puede cumplirse.
+    fn minas_restantes(
+        restricciones: &[Restriccion],
+        conocidas: &[Option<bool>],
+        minas_totales: usize,
+    ) -> Result<usize, ErrorMapa> {
+        for restriccion in restricciones {
+            restriccion.reducir(conocidas)?;
+        }
+        let conocidas = conocidas.iter().filter(|c| **c == Some(true)).count();
+        minas_totales
+            .checked_sub(conocidas)
+            .ok_or(ErrorMapa::PistasInconsistentes)
+    }
+
+    /// Retorna los indices de las celdas desconocidas que no pertenecen a ninguna frontera.
+    fn interiores(conocidas: &[Option<bool>], fronteras: &[Frontera]) -> Vec<usize> {
+        let mut en_frontera = vec![false; conocidas.len()];
+        for &celda in fronteras.iter().flat_map(|f| f.celdas()) {
+            en_frontera[celda] = true;
+        }
+        (0..conocidas.len())
+            .filter(|&i| conocidas[i].is_none() && !en_frontera[i])
+            .collect()
+    }
+
+    /// Retorna, para cada cantidad `t` de minas en las fronteras, un valor proporcional a la cantidad de formas de ubicar las `minas` restantes entre las celdas `interiores`, es decir, al coeficiente binomial `C(interiores, minas - t)`.
+    ///
+    /// Los valores se calculan en escala logarítmica y se normalizan respecto del máximo para evitar desbordamientos en tableros grandes.
+    fn pesos(interiores: usize, minas: usize, maximo: usize) -> Vec<f64> {
+        let mut ln_factorial = vec![0.0; interiores + 1];
+        for i in 1..=interiores {
+            ln_factorial[i] = ln_factorial[i - 1] + (i as f64).ln();
+        }
+        let logaritmos: Vec<Option<f64>> = (0..=maximo)
+            .map(|t| {
+                let m = minas.checked_sub(t).filter(|m| *m <= interiores)?;
+                Some(ln_factorial[interiores] - ln_factorial[m] - ln_factorial[interiores - m])
+            })
+            .collect();
+        let mayor = logaritmos
+            .iter()
+            .flatten()
+            .fold(f64::NEG_INFINITY, |a, b| a.max(*b));
+        logaritmos
+            .iter()
+            .map(|l| l.map_or(0.0, |l| (l - mayor).exp()))
+            .collect()
+    }
+
+    /// Asigna la probabilidad de cada celda de la `frontera`, combinando sus distribuciones con las de las `otras` fronteras y con los `pesos` de las celdas interiores.
+    fn asignar_frontera(
+        frontera: &Frontera,
+        otras: &[f64],
+        pesos: &[f64],
+        normalizacion: f64,
+        valores: &mut [Option<f64>],
+    ) {
+        let mut acumulado = vec![0.0; frontera.celdas().len()];
+        for k in 0..frontera.soluciones().len() {
+            let peso: f64 = otras
+                .iter()
+                .enumerate()
+                .map(|(j, o)| o * pesos.get(k + j).unwrap_or(&0.0))
+                .sum();
+            for (a, m) in acumulado
+                .iter_mut()
+                .zip(frontera.minas_por_celda(k).unwrap_or(&[]))
+            {
+                *a += m * peso;
+            }
+        }
+        for (&celda, a) in frontera.celdas().iter().zip(acumulado) {
+            valores[celda] = Some(a / normalizacion);
+        }
+    }
+
+    /// Retorna la suma, sin normalizar, de las probabilidades de cada celda interior de contener una mina.
+    fn probabilidad_interior(total: &[f64], pesos: &[f64], minas: usize, interiores: usize) -> f64 {
+        if interiores == 0 {
+            return 0.0;
+        }
+        total
+            .iter()
+            .zip(pesos.iter())
+            .enumerate()
+            .map(|(t, (s, p))| s * p * minas.saturating_sub(t) as f64 / interiores as f64)
+            .sum()
+    }
+
+    /// Retorna la cantidad de distribuciones válidas para cada cantidad total de minas, combinando todas las `fronteras`.
+    fn convolucionar_todas<'a>(fronteras: impl Iterator<Item = &'a Frontera>) -> Vec<f64> {
+        fronteras.fold(vec![1.0], |total, frontera| {
+            let mut resultado = vec![0.0; total.len() + frontera.soluciones().len() - 1];
+            for (i, a) in total.iter().enumerate() {
+                for (j, b) in frontera.soluciones().iter().enumerate() {
+                    resultado[i + j] += a * b;
+                }
+            }
+            resultado
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tablero::coordenada::Coordenadas2D;
+    use crate::tablero::tablero_parcial::tests::crear_parcial;
+
+    /// Calcula las probabilidades probando todas las distribuciones posibles de minas entre las celdas desconocidas.
+    fn fuerza_bruta(parcial: &TableroParcial, minas_totales: usize) -> Vec<Option<f64>> {
+        let mut conocidas = parcial.conocidas_iniciales();
+        let restricciones = parcial.restricciones();
+        let desconocidas: Vec<usize> = (0..conocidas.len())
+            .filter(|&i| conocidas[i].is_none())
+            .collect();
+        let minas = minas_totales - conocidas.iter().filter(|c| **c == Some(true)).count();
+        let (mut cuenta, mut total) = (vec![0.0; conocidas.len()], 0.0);
+        for mascara in 0u32..(1 << desconocidas.len()) {
+            if mascara.count_ones() as usize != minas {
+                continue;
+            }
+            for (j, &c) in desconocidas.iter().enumerate() {
+                conocidas[c] = Some(mascara & (1 << j) != 0);
+            }
+            if restricciones.iter().all(|r| r.reducir(&conocidas).is_ok()) {
+                total += 1.0;
+                for (j, &c) in desconocidas.iter().enumerate() {
+                    cuenta[c] += (mascara >> j & 1) as f64;
+                }
+            }
+        }
+        (0..conocidas.len())
+            .map(|i| desconocidas.contains(&i).then(|| cuenta[i] / total))
+            .collect()
+    }
+
+    fn comparar(parcial: &TableroParcial, minas_totales: usize) {
+        let resultado = Probabilidades::calcular(parcial, minas_totales).unwrap();
+        let esperado = fuerza_bruta(parcial, minas_totales);
+        for (r, e) in resultado.valores.iter().zip(esperado.iter()) {
+            match (r, e) {
+                (Some(r), Some(e)) => assert!((r - e).abs() < 1e-9, "{} != {}", r, e),
+                _ => assert_eq!(r, e),
+            }
+        }
+    }
+
+    #[test]
+    fn probabilidades_de_una_frontera() {
+        // 1 ?
+        // ? ?
+        let parcial = crear_parcial("1?\n??\n");
+        let resultado = Probabilidades::calcular(&parcial, 1).unwrap();
+        for (x, y) in [(1, 0), (0, 1), (1, 1)] {
+            let probabilidad = resultado.probabilidad(&Coordenadas2D::new(x, y)).unwrap();
+            assert!((probabilidad - 1.0 / 3.0).abs() < 1e-9);
+        }
+        assert_eq!(resultado.probabilidad(&Coordenadas2D::new(0, 0)), None);
+    }
+
+    #[test]
+    fn probabilidades_con_celdas_interiores() {
+        comparar(&crear_parcial("1??\n???\n???\n"), 2);
+        comparar(&crear_parcial("1??\n???\n???\n"), 5);
+        comparar(&crear_parcial("?2??\n?*??\n????\n"), 4);
+    }
+
+    #[test]
+    fn probabilidades_con_varias_fronteras() {
+        comparar(&crear_parcial("?1..1?\n?1..1?\n??..??\n"), 2);
+        comparar(&crear_parcial("?????\n?121?\n?????\n"), 2);
+    }
+
+    #[test]
+    fn probabilidades_suman_las_minas_restantes() {
+        let parcial = crear_parcial("???????\n?1221??\n???????\n");
+        let resultado = Probabilidades::calcular(&parcial, 5).unwrap();
+        let suma: f64 = resultado.valores.iter().flatten().sum();
+        assert!((suma - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn celda_mas_segura() {
+        // ? ? ?
+        // 1 2 1
+        let parcial = crear_parcial("???\n121\n");
+        let resultado = Probabilidades::calcular(&parcial, 2).unwrap();
+        assert_eq!(resultado.mas_segura(), Some(Coordenadas2D::new(1, 0)));
+        assert_eq!(resultado.probabilidad(&Coordenadas2D::new(1, 0)), Some(0.0));
+    }
+
+    #[test]
+    fn probabilidades_inconsistentes() {
+        let parcial = crear_parcial("1??\n");
+        let resultado = Probabilidades::calcular(&parcial, 0);
+        assert_eq!(resultado, Err(ErrorMapa::PistasInconsistentes));
+        let parcial = crear_parcial("*1?\n");
+        let resultado = Probabilidades::calcular(&parcial, 0);
+        assert_eq!(resultado, Err(ErrorMapa::PistasInconsistentes));
+    }
+
+    #[test]
+    fn frontera_demasiado_grande() {
+        // ? ? ? ? ...
+        // 1 ? 1 ? ...
+        // ? ? ? ? ...
+        let fila = "?".repeat(32);
+        let casilleros = format!("{}\n{}\n{}\n", fila, "1?".repeat(16), fila);
+        let parcial = crear_parcial(&casilleros);
+        let resultado = Probabilidades::calcular(&parcial, 20);
+        assert_eq!(resultado, Err(ErrorMapa::BusquedaExcedida));
+    }
+}
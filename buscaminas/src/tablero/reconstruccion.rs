@@ -0,0 +1,207 @@
+//! # Reconstruccion
+//! `reconstruccion` es un submódulo que contiene a [`Reconstruccion`], el conjunto de tableros completos compatibles con los espacios descubiertos de un [`TableroParcial`].
+//!
+//! [`TableroParcial`]: ../tablero_parcial/struct.TableroParcial.html
+use crate::error::error_mapa::ErrorMapa;
+use crate::tablero::casillero::Casillero;
+use crate::tablero::celda_parcial::CeldaParcial;
+use crate::tablero::enumerador::{Enumerador, PASOS_MAXIMOS};
+use crate::tablero::frontera::Frontera;
+use crate::tablero::restriccion::Restriccion;
+use crate::tablero::tablero_parcial::TableroParcial;
+use crate::tablero::Tablero;
+
+/// Constante que indica la cantidad máxima de soluciones que se buscan al [reconstruir](../tablero_parcial/struct.TableroParcial.html#method.reconstruir) un tablero. Al alcanzarla, la reconstrucción se considera [múltiple][Reconstruccion::Multiple] sin enumerar el resto.
+pub const SOLUCIONES_MAXIMAS: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// `enum` cuyas variantes indican cuántas distribuciones de minas son compatibles con un [`TableroParcial`]. Cada distribución se representa con el [`Tablero`] resuelto que le corresponde.
+pub enum Reconstruccion<const N: usize = 2> {
+    /// Variante que indica que ninguna distribución de minas cumple los números del tablero.
+    Ninguna,
+    /// Variante que indica que existe una única distribución de minas que cumple los números del tablero.
+    Unica(Tablero<N>),
+    /// Variante que indica que existen varias distribuciones de minas que cumplen los números del tablero. Contiene algunas de ellas como testigos, como máximo las indicadas al calcularla.
+    Multiple(Vec<Tablero<N>>),
+}
+
+impl<const N: usize> Reconstruccion<N> {
+    /// Calcula la [`Reconstruccion`] del tablero `parcial`. Si se indican las `minas_totales`, solo se consideran las distribuciones con esa cantidad de minas. Se detiene al encontrar `limite` soluciones.
+    ///
+    /// # Errores
+    ///
+    /// - Retorna [`BusquedaExcedida`][ErrorMapa::BusquedaExcedida] si la búsqueda supera la cantidad máxima de pasos antes de encontrar `limite` soluciones o de descartar el resto.
+    /// - Retornara el error dado al [resolver][Tablero::resolver] cada una de las soluciones.
+    pub(crate) fn calcular(
+        parcial: &TableroParcial<N>,
+        minas_totales: Option<usize>,
+        limite: usize,
+    ) -> Result<Reconstruccion<N>, ErrorMapa> {
+        let mut conocidas = parcial.conocidas_iniciales();
+        let mut restricciones = parcial.restricciones();
+        let celdas = Self::orden_de_asignacion(&restricciones, &conocidas);
+        if let Some(minas) = minas_totales {
+            restricciones.push(Restriccion::new((0..conocidas.len()).collect(), minas));
+        }
+        if restricciones.iter().any(|r| r.reducir(&conocidas).is_err()) {
+            return Ok(Reconstruccion::Ninguna);
+        }
+        let mut asignaciones = vec![];
+        let mut pasos = PASOS_MAXIMOS;
+        let enumerador = Enumerador::new(&celdas, &restricciones, conocidas.len());
+        enumerador.recorrer(&mut conocidas, &mut pasos, &mut |asignacion| {
+            asignaciones.push(asignacion.to_vec());
+            asignaciones.len() < limite
+        })?;
+        let soluciones = asignaciones
+            .iter()
+            .map(|a| Self::completar(parcial, a))
+            .collect::<Result<Vec<Tablero<N>>, ErrorMapa>>()?;
+        Ok(Self::clasificar(soluciones))
+    }
+
+    /// Retorna la variante de [`Reconstruccion`] que corresponde a la cantidad de `soluciones`.
+    fn clasificar(mut soluciones: Vec<Tablero<N>>) -> Reconstruccion<N> {
+        match soluciones.len() {
+            0 => Reconstruccion::Ninguna,
+            1 => Reconstruccion::Unica(soluciones.remove(0)),
+            _ => Reconstruccion::Multiple(soluciones),
+        }
+    }
+
+    /// Retorna los tableros compatibles encontrados.
+    pub fn soluciones(&self) -> &[Tablero<N>] {
+        match self {
+            Reconstruccion::Ninguna => &[],
+            Reconstruccion::Unica(tablero) => std::slice::from_ref(tablero),
+            Reconstruccion::Multiple(tableros) => tableros,
+        }
+    }
+
+    /// Retorna `true` si existe una única distribución de minas compatible.
+    pub fn es_unica(&self) -> bool {
+        matches!(self, Reconstruccion::Unica(_))
+    }
+
+    /// Retorna las celdas desconocidas en el orden en que conviene asignarlas: primero las de cada [`Frontera`], de modo que las restricciones se verifiquen lo antes posible, y por último las que no pertenecen a ninguna.
+    fn orden_de_asignacion(
+        restricciones: &[Restriccion],
+        conocidas: &[Option<bool>],
+    ) -> Vec<usize> {
+        let mut celdas: Vec<usize> = Frontera::separar(restricciones, conocidas)
+            .into_iter()
+            .flatten()
+            .collect();
+        let mut incluidas = vec![false; conocidas.len()];
+        for &celda in &celdas {
+            incluidas[celda] = true;
+        }
+        celdas.extend((0..conocidas.len()).filter(|&i| conocidas[i].is_none() && !incluidas[i]));
+        celdas
+    }
+
+    /// Construye el [`Tablero`] resuelto en el que las minas son exactamente las celdas marcadas como tales en la `asignacion`. Las paredes del tablero `parcial` se conservan.
+    ///
+    /// # Errores
+    ///
+    /// Retornara el error dado al [resolver][Tablero::resolver] el tablero.
+    fn completar(
+        parcial: &TableroParcial<N>,
+        asignacion: &[Option<bool>],
+    ) -> Result<Tablero<N>, ErrorMapa> {
+        let mapa = asignacion
+            .iter()
+            .zip(parcial.celdas())
+            .map(|(a, celda)| match (a, celda) {
+                (_, CeldaParcial::Pared) => Casillero::Pared,
+                (Some(true), _) => Casillero::Mina(1),
+                _ => Casillero::Espacio(0),
+            })
+            .collect();
+        Tablero {
+            dimensiones: parcial.dimensiones(),
+            topologia: parcial.topologia(),
+            vecindario: parcial.vecindario().clone(),
+            simbolos: parcial.simbolos().clone(),
+            mapa,
+        }
+        .resolver()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tablero::builder::TableroBuilder;
+    use crate::tablero::tablero_parcial::tests::crear_parcial;
+
+    fn crear_tablero(casilleros: &str) -> Tablero {
+        TableroBuilder::new(casilleros)
+            .unwrap()
+            .crear_tablero()
+            .unwrap()
+    }
+
+    #[test]
+    fn reconstruir_tablero_unico() {
+        let parcial = crear_parcial("1??22?\n13?22?\n");
+        let resultado = Reconstruccion::calcular(&parcial, None, usize::MAX);
+        let esperado = crear_tablero(".**..*\n..*..*\n");
+        assert_eq!(resultado, Ok(Reconstruccion::Unica(esperado)));
+    }
+
+    #[test]
+    fn reconstruir_tablero_ambiguo() {
+        // ? 1
+        // 1 ?
+        let parcial = crear_parcial("?1\n1?\n");
+        let resultado = Reconstruccion::calcular(&parcial, None, usize::MAX).unwrap();
+        let esperado = vec![crear_tablero("..\n.*\n"), crear_tablero("*.\n..\n")];
+        assert_eq!(resultado, Reconstruccion::Multiple(esperado));
+        assert!(!resultado.es_unica());
+    }
+
+    #[test]
+    fn reconstruir_tablero_inconsistente() {
+        let parcial = crear_parcial("?3?\n");
+        let resultado = Reconstruccion::calcular(&parcial, None, usize::MAX);
+        assert_eq!(resultado, Ok(Reconstruccion::Ninguna));
+
+        let parcial = crear_parcial("1.\n");
+        let resultado = Reconstruccion::calcular(&parcial, None, usize::MAX);
+        assert_eq!(resultado, Ok(Reconstruccion::Ninguna));
+    }
+
+    #[test]
+    fn reconstruir_con_limite() {
+        let parcial = crear_parcial("???\n???\n");
+        let resultado = Reconstruccion::calcular(&parcial, None, 3).unwrap();
+        assert_eq!(resultado.soluciones().len(), 3);
+    }
+
+    #[test]
+    fn reconstruir_tablero_sin_restricciones() {
+        let parcial = crear_parcial(&"?".repeat(100));
+        let resultado = parcial.reconstruir().unwrap();
+        assert!(!resultado.es_unica());
+        assert_eq!(resultado.soluciones().len(), SOLUCIONES_MAXIMAS);
+    }
+
+    #[test]
+    fn reconstruir_con_minas_totales() {
+        // ? 1 ?
+        let parcial = crear_parcial("?1?\n");
+        let resultado = Reconstruccion::calcular(&parcial, Some(1), usize::MAX).unwrap();
+        assert_eq!(resultado.soluciones().len(), 2);
+        let resultado = Reconstruccion::calcular(&parcial, Some(2), usize::MAX).unwrap();
+        assert_eq!(resultado, Reconstruccion::Ninguna);
+    }
+
+    #[test]
+    fn reconstruir_excede_los_pasos() {
+        let fila = "?".repeat(32);
+        let casilleros = format!("{}\n{}\n{}\n", fila, "1?".repeat(16), fila);
+        let resultado = Reconstruccion::calcular(&crear_parcial(&casilleros), Some(20), 2);
+        assert_eq!(resultado, Err(ErrorMapa::BusquedaExcedida));
+    }
+}
@@ -0,0 +1,172 @@
+//! Restriccion
+//!
+//! `restriccion` es un submódulo que contiene a [`Restriccion`], la unidad de información con la que se razona sobre un [`TableroParcial`].
+//!
+//! [`TableroParcial`]: ../tablero_parcial/struct.TableroParcial.html
+
+use crate::error::error_mapa::ErrorMapa;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Estructura que indica que entre un conjunto de celdas hay exactamente una cantidad determinada de minas.
+///
+/// Cada espacio descubierto de un [`TableroParcial`](../tablero_parcial/struct.TableroParcial.html) da lugar a una restricción sobre sus celdas adyacentes.
+pub(crate) struct Restriccion {
+    /// Indices de las celdas involucradas en la restricción.
+    celdas: Vec<usize>,
+    /// Cantidad de minas que hay entre las [celdas][Restriccion::celdas].
+    minas: usize,
+}
+
+impl Restriccion {
+    /// Construye una nueva [`Restriccion`] sobre las `celdas` indicadas.
+    pub(crate) fn new(celdas: Vec<usize>, minas: usize) -> Restriccion {
+        Restriccion { celdas, minas }
+    }
+
+    /// Retorna una nueva [`Restriccion`] que solo involucra a las celdas cuyo contenido todavía no se conoce.
+    ///
+    /// `conocidas` indica para cada celda del tablero si se sabe que es una mina (`Some(true)`), si se sabe que no lo es (`Some(false)`) o si se desconoce (`None`). Las minas conocidas se descuentan de la cantidad de minas de la restricción.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`PistasInconsistentes`][ErrorMapa::PistasInconsistentes] si las celdas conocidas hacen imposible cumplir la restricción.
+    pub(crate) fn reducir(&self, conocidas: &[Option<bool>]) -> Result<Restriccion, ErrorMapa> {
+        let minas_conocidas = self
+            .celdas
+            .iter()
+            .filter(|&&c| conocidas[c] == Some(true))
+            .count();
+        let celdas: Vec<usize> = self
+            .celdas
+            .iter()
+            .filter(|&&c| conocidas[c].is_none())
+            .copied()
+            .collect();
+        match self.minas.checked_sub(minas_conocidas) {
+            Some(minas) if minas <= celdas.len() => Ok(Restriccion::new(celdas, minas)),
+            _ => Err(ErrorMapa::PistasInconsistentes),
+        }
+    }
+
+    /// Retorna `true` si la restricción todavía puede cumplirse con las celdas `conocidas`. Equivale a que [`reducir`][Restriccion::reducir] no falle, pero sin construir la restricción reducida.
+    pub(crate) fn admite(&self, conocidas: &[Option<bool>]) -> bool {
+        let (mut minas, mut desconocidas) = (0, 0);
+        for &celda in &self.celdas {
+            match conocidas[celda] {
+                Some(true) => minas += 1,
+                None => desconocidas += 1,
+                Some(false) => {}
+            }
+        }
+        minas <= self.minas && self.minas - minas <= desconocidas
+    }
+
+    /// Compara esta restricción con `otra` que comparte celdas con ella. Si las minas que faltan ubicar en esta superan a las de `otra` en tantas como celdas propias (que no pertenecen a `otra`) tiene, entonces todas sus celdas propias son minas y todas las celdas propias de `otra` son seguras.
+    ///
+    /// Retorna las celdas que son minas y las que son seguras, o `None` si no se puede deducir nada. Por ejemplo, si entre `{a, b, c}` hay 2 minas y entre `{a, b}` hay 1, entonces `c` es una mina.
+    pub(crate) fn comparar(&self, otra: &Restriccion) -> Option<(Vec<usize>, Vec<usize>)> {
+        let propias = Self::diferencia(&self.celdas, &otra.celdas);
+        let ajenas = Self::diferencia(&otra.celdas, &self.celdas);
+        let solapadas = propias.len() < self.celdas.len();
+        match self.minas.checked_sub(otra.minas) {
+            Some(d) if solapadas && d == propias.len() && propias.len() + ajenas.len() > 0 => {
+                Some((propias, ajenas))
+            }
+            _ => None,
+        }
+    }
+
+    /// Retorna las celdas de `a` que no pertenecen a `b`.
+    fn diferencia(a: &[usize], b: &[usize]) -> Vec<usize> {
+        a.iter().filter(|c| !b.contains(c)).copied().collect()
+    }
+
+    /// Retorna `Some(true)` si todas las celdas de la restricción son minas, `Some(false)` si ninguna lo es y `None` si no puede determinarse.
+    pub(crate) fn deducir(&self) -> Option<bool> {
+        match self.minas {
+            0 => Some(false),
+            m if m == self.celdas.len() => Some(true),
+            _ => None,
+        }
+    }
+
+    /// Retorna los indices de las celdas involucradas en la restricción.
+    pub(crate) fn celdas(&self) -> &[usize] {
+        &self.celdas
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reducir_restriccion_con_celdas_conocidas() {
+        let restriccion = Restriccion::new(vec![0, 1, 2, 3], 2);
+        let conocidas = [Some(true), None, Some(false), None];
+        let esperado = Restriccion::new(vec![1, 3], 1);
+        assert_eq!(restriccion.reducir(&conocidas), Ok(esperado));
+    }
+
+    #[test]
+    fn reducir_restriccion_inconsistente() {
+        let restriccion = Restriccion::new(vec![0, 1, 2], 1);
+        let conocidas = [Some(true), Some(true), None];
+        let resultado = restriccion.reducir(&conocidas);
+        assert_eq!(resultado, Err(ErrorMapa::PistasInconsistentes));
+
+        let conocidas = [Some(false), Some(false), Some(false)];
+        let resultado = restriccion.reducir(&conocidas);
+        assert_eq!(resultado, Err(ErrorMapa::PistasInconsistentes));
+    }
+
+    #[test]
+    fn admite_equivale_a_reducir() {
+        let restriccion = Restriccion::new(vec![0, 1, 2], 1);
+        for conocidas in [
+            [None, None, None],
+            [Some(true), None, Some(false)],
+            [Some(true), Some(true), None],
+            [Some(false), Some(false), Some(false)],
+        ] {
+            let esperado = restriccion.reducir(&conocidas).is_ok();
+            assert_eq!(restriccion.admite(&conocidas), esperado);
+        }
+    }
+
+    #[test]
+    fn comparar_restricciones_contenidas() {
+        let a = Restriccion::new(vec![0, 1, 2], 2);
+        let b = Restriccion::new(vec![0, 1], 1);
+        assert_eq!(a.comparar(&b), Some((vec![2], vec![])));
+        assert_eq!(b.comparar(&a), None);
+
+        let c = Restriccion::new(vec![0, 1, 2], 1);
+        assert_eq!(b.comparar(&c), Some((vec![], vec![2])));
+    }
+
+    #[test]
+    fn comparar_restricciones_solapadas() {
+        // Patrón 1-2: {0, 1, 2} tiene 2 minas y {1, 2, 3} tiene 1.
+        let a = Restriccion::new(vec![0, 1, 2], 2);
+        let b = Restriccion::new(vec![1, 2, 3], 1);
+        assert_eq!(a.comparar(&b), Some((vec![0], vec![3])));
+        assert_eq!(b.comparar(&a), None);
+    }
+
+    #[test]
+    fn comparar_restricciones_sin_informacion() {
+        let a = Restriccion::new(vec![0, 1], 1);
+        let b = Restriccion::new(vec![2, 3], 1);
+        assert_eq!(a.comparar(&b), None);
+        assert_eq!(a.comparar(&a), None);
+    }
+
+    #[test]
+    fn deducir_restriccion() {
+        assert_eq!(Restriccion::new(vec![0, 1], 0).deducir(), Some(false));
+        assert_eq!(Restriccion::new(vec![0, 1], 2).deducir(), Some(true));
+        assert_eq!(Restriccion::new(vec![0, 1], 1).deducir(), None);
+        assert_eq!(Restriccion::new(vec![], 0).deducir(), Some(false));
+    }
+}
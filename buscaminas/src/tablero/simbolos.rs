@@ -0,0 +1,251 @@
+//! # Simbolos
+//! `simbolos` es un submódulo que contiene a [`Simbolos`], el conjunto de caracteres con el que se leen y se muestran las celdas de un [`Tablero`].
+//!
+//! [`Tablero`]: ../struct.Tablero.html
+
+use crate::error::error_mapa::ErrorMapa;
+use crate::tablero::builder::{
+    ANTIMINA_ICONO, ESPACIO_ICONO, MINA_DOBLE_ICONO, MINA_ICONO, MINA_TRIPLE_ICONO, PARED_ICONO,
+};
+use crate::tablero::casillero::Casillero;
+use crate::tablero::celda_parcial::CeldaParcial;
+use crate::tablero::partida::{MARCA_ICONO, OCULTA_ICONO};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Estructura que contiene el caracter con el que se representa cada tipo de celda, tanto al leer un mapa con el [`TableroBuilder`] como al mostrar un [`Tablero`], un [`TableroParcial`] o una [`Partida`].
+///
+/// Por defecto se utilizan las constantes del [builder][crate::tablero::builder]. Cada caracter puede ser cualquier `char`, incluso un emoji, siempre que ocupe un único punto de código. Los caracteres deben ser distintos entre sí y, salvo el de los espacios que puede ser `0`, no pueden ser dígitos, ya que los dígitos representan la cantidad de minas adyacentes. Tampoco pueden ser espacios en blanco, que separan las celdas de los tableros hexagonales y las filas, ni `-`, que precede a los recuentos negativos.
+///
+/// # Ejemplos
+/// ```
+/// # use buscaminas::tablero::builder::TableroBuilder;
+/// # use buscaminas::tablero::simbolos::Simbolos;
+/// # use buscaminas::error::error_mapa::ErrorMapa;
+/// #
+/// # fn main() -> Result<(),ErrorMapa> {
+/// let entrada = Simbolos { mina: 'X', espacio: '0', ..Simbolos::default() };
+/// let salida = Simbolos { mina: '💣', espacio: '_', ..Simbolos::default() };
+/// let builder = TableroBuilder::new("X0\n00\n")?.con_simbolos(entrada)?;
+/// let resultado = builder.crear_tablero()?.con_simbolos(salida);
+/// assert_eq!(resultado.to_string(), "💣1\n11\n");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`TableroBuilder`]: ../builder/struct.TableroBuilder.html
+/// [`Tablero`]: ../struct.Tablero.html
+/// [`TableroParcial`]: ../tablero_parcial/struct.TableroParcial.html
+/// [`Partida`]: ../partida/struct.Partida.html
+pub struct Simbolos {
+    /// Caracter de las celdas con una mina.
+    pub mina: char,
+    /// Caracter de las celdas con dos minas.
+    pub mina_doble: char,
+    /// Caracter de las celdas con tres minas.
+    pub mina_triple: char,
+    /// Caracter de las anti-minas.
+    pub antimina: char,
+    /// Caracter de las paredes.
+    pub pared: char,
+    /// Caracter de los espacios sin minas adyacentes.
+    pub espacio: char,
+    /// Caracter de las celdas que todavía no fueron descubiertas.
+    pub oculta: char,
+    /// Caracter de las celdas marcadas por el jugador.
+    pub marca: char,
+}
+
+impl Default for Simbolos {
+    fn default() -> Self {
+        Simbolos {
+            mina: MINA_ICONO as char,
+            mina_doble: MINA_DOBLE_ICONO as char,
+            mina_triple: MINA_TRIPLE_ICONO as char,
+            antimina: ANTIMINA_ICONO as char,
+            pared: PARED_ICONO as char,
+            espacio: ESPACIO_ICONO as char,
+            oculta: OCULTA_ICONO as char,
+            marca: MARCA_ICONO as char,
+        }
+    }
+}
+
+impl Simbolos {
+    /// Retorna el [`Casillero`] sin resolver representado por el `caracter`, o `None` si no corresponde a ninguno.
+    pub(crate) fn casillero(&self, caracter: char) -> Option<Casillero> {
+        match caracter {
+            c if c == self.mina => Some(Casillero::Mina(1)),
+            c if c == self.mina_doble => Some(Casillero::Mina(2)),
+            c if c == self.mina_triple => Some(Casillero::Mina(3)),
+            c if c == self.antimina => Some(Casillero::AntiMina),
+            c if c == self.pared => Some(Casillero::Pared),
+            c if c == self.espacio => Some(Casillero::Espacio(0)),
+            _ => None,
+        }
+    }
+
+    /// Retorna la [`CeldaParcial`] representada por el `caracter`, o `None` si no corresponde a ninguna. Los dígitos `0` a `8` representan espacios descubiertos.
+    pub(crate) fn celda_parcial(&self, caracter: char) -> Option<CeldaParcial> {
+        match caracter {
+            c if c == self.oculta => Some(CeldaParcial::Desconocida),
+            c if c == self.pared => Some(CeldaParcial::Pared),
+            c if c == self.mina => Some(CeldaParcial::Mina),
+            c if c == self.espacio => Some(CeldaParcial::Descubierta(0)),
+            '0'..='8' => Some(CeldaParcial::Descubierta(caracter as u8 - b'0')),
+            _ => None,
+        }
+    }
+
+    /// Retorna el texto con el que se muestra el `casillero`. Los espacios con minas adyacentes se muestran con su cantidad.
+    pub fn representar(&self, casillero: &Casillero) -> String {
+        match casillero {
+            Casillero::Espacio(0) => self.espacio.to_string(),
+            Casillero::Espacio(x) => x.to_string(),
+            Casillero::Mina(2) => self.mina_doble.to_string(),
+            Casillero::Mina(3) => self.mina_triple.to_string(),
+            Casillero::Mina(_) => self.mina.to_string(),
+            Casillero::AntiMina => self.antimina.to_string(),
+            Casillero::Pared => self.pared.to_string(),
+        }
+    }
+
+    /// Retorna el `texto` de un tablero escrito con estos símbolos, reescrito con los símbolos de `destino`. Los dígitos, los signos de los recuentos negativos y los espacios en blanco se conservan.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::simbolos::Simbolos;
+    /// # use buscaminas::error::error_mapa::ErrorMapa;
+    /// #
+    /// # fn main() -> Result<(),ErrorMapa> {
+    /// let destino = Simbolos { mina: 'X', espacio: '0', ..Simbolos::default() };
+    /// let convertido = Simbolos::default().convertir("*1.\n?1.\n", &destino)?;
+    /// assert_eq!(convertido, "X10\n?10\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`CaracterDesconocido`][ErrorMapa::CaracterDesconocido] si el texto contiene un caracter que no pertenece a estos símbolos.
+    pub fn convertir(&self, texto: &str, destino: &Simbolos) -> Result<String, ErrorMapa> {
+        let (origen, destino) = (self.caracteres(), destino.caracteres());
+        texto
+            .chars()
+            .map(|c| match origen.iter().position(|o| *o == c) {
+                Some(indice) => Ok(destino[indice]),
+                None if c.is_ascii_digit() || c.is_whitespace() || c == '-' => Ok(c),
+                None => Err(ErrorMapa::CaracterDesconocido(c)),
+            })
+            .collect()
+    }
+
+    /// Verifica que los caracteres del conjunto sean distintos entre sí y que ninguno esté [reservado][Simbolos::es_reservado].
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`SimbolosInvalidos`][ErrorMapa::SimbolosInvalidos] con el primer caracter repetido o reservado.
+    pub(crate) fn validar(&self) -> Result<(), ErrorMapa> {
+        let caracteres = self.caracteres();
+        for (i, c) in caracteres.iter().enumerate() {
+            if self.es_reservado(*c) || caracteres[..i].contains(c) {
+                return Err(ErrorMapa::SimbolosInvalidos(*c));
+            }
+        }
+        Ok(())
+    }
+
+    /// Retorna `true` si el caracter `c` tiene otro significado en el texto de un tablero, por lo que no puede usarse como símbolo: los dígitos, salvo el `0` de los espacios, los espacios en blanco y el signo `-` de los recuentos negativos, que [convertir][Simbolos::convertir] conserva sin cambios.
+    fn es_reservado(&self, c: char) -> bool {
+        let es_digito = c.is_ascii_digit() && !(c == '0' && c == self.espacio);
+        es_digito || c.is_whitespace() || c == '-'
+    }
+
+    /// Retorna todos los caracteres del conjunto, siempre en el mismo orden.
+    fn caracteres(&self) -> [char; 8] {
+        [
+            self.mina,
+            self.mina_doble,
+            self.mina_triple,
+            self.antimina,
+            self.pared,
+            self.espacio,
+            self.oculta,
+            self.marca,
+        ]
+    }
+
+    /// Retorna el texto con el que se muestra la `celda` de un [`TableroParcial`](../tablero_parcial/struct.TableroParcial.html).
+    pub fn representar_parcial(&self, celda: &CeldaParcial) -> String {
+        match celda {
+            CeldaParcial::Desconocida => self.oculta.to_string(),
+            CeldaParcial::Descubierta(0) => self.espacio.to_string(),
+            CeldaParcial::Descubierta(n) => n.to_string(),
+            CeldaParcial::Mina => self.mina.to_string(),
+            CeldaParcial::Pared => self.pared.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simbolos_por_defecto_usan_las_constantes() {
+        let simbolos = Simbolos::default();
+        assert_eq!(simbolos.casillero('*'), Some(Casillero::Mina(1)));
+        assert_eq!(simbolos.casillero('&'), Some(Casillero::Mina(3)));
+        assert_eq!(simbolos.casillero('X'), None);
+        assert_eq!(simbolos.representar(&Casillero::Espacio(0)), ".");
+        assert_eq!(simbolos.representar(&Casillero::Espacio(-2)), "-2");
+    }
+
+    #[test]
+    fn simbolos_personalizados() {
+        let simbolos = Simbolos {
+            mina: '💣',
+            pared: '🧱',
+            ..Simbolos::default()
+        };
+        assert_eq!(simbolos.casillero('💣'), Some(Casillero::Mina(1)));
+        assert_eq!(simbolos.casillero('*'), None);
+        assert_eq!(simbolos.celda_parcial('🧱'), Some(CeldaParcial::Pared));
+        assert_eq!(
+            simbolos.celda_parcial('3'),
+            Some(CeldaParcial::Descubierta(3))
+        );
+        assert_eq!(simbolos.representar(&Casillero::Pared), "🧱");
+    }
+
+    #[test]
+    fn validar_simbolos() {
+        let espacio_cero = Simbolos {
+            espacio: '0',
+            ..Simbolos::default()
+        };
+        assert_eq!(espacio_cero.validar(), Ok(()));
+        let repetido = Simbolos {
+            mina: '#',
+            ..Simbolos::default()
+        };
+        assert_eq!(repetido.validar(), Err(ErrorMapa::SimbolosInvalidos('#')));
+        let digito = Simbolos {
+            pared: '3',
+            ..Simbolos::default()
+        };
+        assert_eq!(digito.validar(), Err(ErrorMapa::SimbolosInvalidos('3')));
+        let cero = Simbolos {
+            mina: '0',
+            ..Simbolos::default()
+        };
+        assert_eq!(cero.validar(), Err(ErrorMapa::SimbolosInvalidos('0')));
+        for reservado in [' ', '\n', '\r', '\t', '-'] {
+            let simbolos = Simbolos {
+                espacio: reservado,
+                ..Simbolos::default()
+            };
+            let esperado = Err(ErrorMapa::SimbolosInvalidos(reservado));
+            assert_eq!(simbolos.validar(), esperado);
+        }
+    }
+}
@@ -0,0 +1,579 @@
+//! # TableroParcial
+//! `tablero_parcial` es un submódulo que contiene a [`TableroParcial`], la representación de un tablero del que solo se conoce una parte, y el razonamiento lógico para descubrir el resto.
+
+use crate::error::error_mapa::ErrorMapa;
+use crate::tablero::celda_parcial::CeldaParcial;
+use crate::tablero::coordenada::Coordenadas;
+use crate::tablero::deduccion::Deduccion;
+use crate::tablero::mostrar_celdas;
+use crate::tablero::probabilidades::Probabilidades;
+use crate::tablero::reconstruccion::{Reconstruccion, SOLUCIONES_MAXIMAS};
+use crate::tablero::restriccion::Restriccion;
+use crate::tablero::simbolos::Simbolos;
+use crate::tablero::topologia::Topologia;
+use crate::tablero::unicidad::Unicidad;
+use crate::tablero::vecindario::Vecindario;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Estructura que contiene lo que se conoce de un tablero revelado parcialmente, como el que ve un jugador durante una [Partida](../partida/struct.Partida.html).
+///
+/// Al igual que el [Tablero](../struct.Tablero.html), puede tener cualquier cantidad `N` de dimensiones, y la deducción, las probabilidades, la reconstrucción y la verificación de unicidad son las mismas en todos los casos.
+pub struct TableroParcial<const N: usize = 2> {
+    /// Campo utilizado para indicar la cantidad de celdas en cada dimensión del tablero.
+    dimensiones: [usize; N],
+    /// Campo utilizado para indicar cómo se unen los bordes del tablero.
+    topologia: Topologia,
+    /// Campo utilizado para indicar qué celdas son adyacentes entre sí.
+    vecindario: Vecindario,
+    /// Campo utilizado para indicar los caracteres con los que se muestra cada celda.
+    simbolos: Simbolos,
+    /// Campo que contiene lo que se conoce de cada celda, con la misma numeración que el mapa de un [Tablero](../struct.Tablero.html).
+    celdas: Vec<CeldaParcial>,
+}
+
+impl<const N: usize> fmt::Display for TableroParcial<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let celdas: Vec<String> = self
+            .celdas
+            .iter()
+            .map(|c| self.simbolos.representar_parcial(c))
+            .collect();
+        mostrar_celdas(f, self.dimensiones, &self.vecindario, &celdas)
+    }
+}
+
+impl<const N: usize> TableroParcial<N> {
+    /// Construye un nuevo [`TableroParcial`] con las `dimensiones`, la [`Topologia`], el [`Vecindario`], los [`Simbolos`] y las `celdas` indicadas.
+    pub(crate) fn new(
+        dimensiones: [usize; N],
+        topologia: Topologia,
+        vecindario: Vecindario,
+        simbolos: Simbolos,
+        celdas: Vec<CeldaParcial>,
+    ) -> TableroParcial<N> {
+        TableroParcial {
+            dimensiones,
+            topologia,
+            vecindario,
+            simbolos,
+            celdas,
+        }
+    }
+
+    /// Retorna la [`Deduccion`] con todas las celdas desconocidas cuyo contenido puede determinarse con certeza a partir de los espacios descubiertos.
+    ///
+    /// Se aplican repetidamente las siguientes reglas hasta que no se obtiene información nueva:
+    /// - Si un espacio ya alcanzó su cantidad de minas adyacentes, el resto de sus celdas desconocidas son seguras.
+    /// - Si la cantidad de celdas desconocidas adyacentes a un espacio es igual a la cantidad de minas que le faltan, todas son minas.
+    /// - Si dos espacios comparten celdas desconocidas, se razona sobre la diferencia entre ambos conjuntos de celdas (por ejemplo, el patrón *1-2*).
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::builder::TableroBuilder;
+    /// # use buscaminas::tablero::coordenada::Coordenadas2D;
+    /// # use buscaminas::error::error_mapa::ErrorMapa;
+    /// #
+    /// # fn main() -> Result<(),ErrorMapa> {
+    /// // ? ? ?
+    /// // 1 2 1
+    /// let parcial = TableroBuilder::new("???\n121\n")?.crear_tablero_parcial()?;
+    /// let deduccion = parcial.deducir()?;
+    /// assert_eq!(deduccion.minas(), &[Coordenadas2D::new(0, 0), Coordenadas2D::new(2, 0)]);
+    /// assert_eq!(deduccion.seguras(), &[Coordenadas2D::new(1, 0)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`PistasInconsistentes`][ErrorMapa::PistasInconsistentes] si los espacios descubiertos se contradicen entre sí.
+    pub fn deducir(&self) -> Result<Deduccion<N>, ErrorMapa> {
+        let mut conocidas = self.conocidas_iniciales();
+        let restricciones = self.restricciones();
+        while Self::aplicar_reglas(&restricciones, &mut conocidas)?
+            || Self::aplicar_reglas_de_pares(&restricciones, &mut conocidas)?
+        {}
+        Ok(self.construir_deduccion(&conocidas))
+    }
+
+    /// Retorna las [`Probabilidades`] de que cada celda desconocida contenga una mina, sabiendo que el tablero contiene en total `minas_totales` minas.
+    ///
+    /// Resulta útil cuando la [deducción][TableroParcial::deducir] no encuentra ninguna celda segura y es necesario arriesgar.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::builder::TableroBuilder;
+    /// # use buscaminas::tablero::coordenada::Coordenadas2D;
+    /// # use buscaminas::error::error_mapa::ErrorMapa;
+    /// #
+    /// # fn main() -> Result<(),ErrorMapa> {
+    /// // 1 ?
+    /// // ? ?
+    /// let parcial = TableroBuilder::new("1?\n??\n")?.crear_tablero_parcial()?;
+    /// let probabilidades = parcial.calcular_probabilidades(1)?;
+    /// let p = probabilidades.probabilidad(&Coordenadas2D::new(1, 1)).unwrap();
+    /// assert!((p - 1.0 / 3.0).abs() < 1e-9);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`PistasInconsistentes`][ErrorMapa::PistasInconsistentes] si no existe ninguna distribución de minas compatible con el tablero y la cantidad total de minas, o [`BusquedaExcedida`][ErrorMapa::BusquedaExcedida] si las celdas adyacentes a los espacios descubiertos admiten demasiadas distribuciones para enumerarlas.
+    pub fn calcular_probabilidades(
+        &self,
+        minas_totales: usize,
+    ) -> Result<Probabilidades<N>, ErrorMapa> {
+        Probabilidades::calcular(self, minas_totales)
+    }
+
+    /// Retorna la [`Reconstruccion`] con todas las distribuciones de minas compatibles con los espacios descubiertos, indicando si no existe ninguna, si es única o si hay varias.
+    ///
+    /// Está pensado para tableros en los que todos los espacios están descubiertos y solo se ocultaron las minas, como la [solución](../struct.Tablero.html) de un problema con las minas reemplazadas por `?`. Como la cantidad de distribuciones crece de forma exponencial con las celdas desconocidas que no están adyacentes a ningún espacio descubierto, la búsqueda se detiene al encontrar [`SOLUCIONES_MAXIMAS`] y la reconstrucción [múltiple][Reconstruccion::Multiple] contiene solo esas.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::builder::TableroBuilder;
+    /// # use buscaminas::tablero::reconstruccion::Reconstruccion;
+    /// # use buscaminas::error::error_mapa::ErrorMapa;
+    /// #
+    /// # fn main() -> Result<(),ErrorMapa> {
+    /// let parcial = TableroBuilder::new("1?1\n")?.crear_tablero_parcial()?;
+    /// let solucion = TableroBuilder::new(".*.\n")?.crear_tablero()?;
+    /// assert_eq!(parcial.reconstruir()?, Reconstruccion::Unica(solucion));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errores
+    ///
+    /// - Retorna [`BusquedaExcedida`][ErrorMapa::BusquedaExcedida] si la búsqueda supera la cantidad máxima de pasos antes de terminar.
+    /// - Retornara el error dado al [resolver](../struct.Tablero.html) cada una de las soluciones.
+    pub fn reconstruir(&self) -> Result<Reconstruccion<N>, ErrorMapa> {
+        Reconstruccion::calcular(self, None, SOLUCIONES_MAXIMAS)
+    }
+
+    /// Verifica si el problema formado por este tablero y la cantidad total de minas tiene una única solución. Si tiene más de una, la [`Unicidad`] contiene dos soluciones distintas como testigos. Si admite demasiadas distribuciones de minas para decidirlo, la [`Unicidad`] es [indeterminada][Unicidad::Indeterminada].
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::builder::TableroBuilder;
+    /// # use buscaminas::tablero::coordenada::Coordenadas2D;
+    /// # use buscaminas::error::error_mapa::ErrorMapa;
+    /// #
+    /// # fn main() -> Result<(),ErrorMapa> {
+    /// // ? 1
+    /// // 1 ?
+    /// let parcial = TableroBuilder::new("?1\n1?\n")?.crear_tablero_parcial()?;
+    /// let unicidad = parcial.verificar_unicidad(1)?;
+    /// assert!(!unicidad.es_unica());
+    /// assert_eq!(unicidad.diferencias(), vec![Coordenadas2D::new(0, 0), Coordenadas2D::new(1, 1)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errores
+    ///
+    /// Retornara el error dado al [resolver](../struct.Tablero.html) cada una de las soluciones.
+    pub fn verificar_unicidad(&self, minas_totales: usize) -> Result<Unicidad<N>, ErrorMapa> {
+        Unicidad::verificar(self, minas_totales)
+    }
+
+    /// Retorna la cantidad de celdas del tablero en cada una de sus dimensiones.
+    pub fn dimensiones(&self) -> [usize; N] {
+        self.dimensiones
+    }
+
+    /// Retorna la [`Topologia`] del tablero.
+    pub fn topologia(&self) -> Topologia {
+        self.topologia
+    }
+
+    /// Retorna el [`Vecindario`] del tablero.
+    pub fn vecindario(&self) -> &Vecindario {
+        &self.vecindario
+    }
+
+    /// Retorna los [`Simbolos`] con los que se muestra el tablero.
+    pub fn simbolos(&self) -> &Simbolos {
+        &self.simbolos
+    }
+
+    /// Retorna lo que se conoce de cada una de las celdas del tablero.
+    pub(crate) fn celdas(&self) -> &[CeldaParcial] {
+        &self.celdas
+    }
+
+    /// Retorna, para cada celda, si se sabe que es una mina (`Some(true)`), si se sabe que no lo es (`Some(false)`) o si se desconoce (`None`).
+    pub(crate) fn conocidas_iniciales(&self) -> Vec<Option<bool>> {
+        self.celdas
+            .iter()
+            .map(|c| match c {
+                CeldaParcial::Desconocida => None,
+                CeldaParcial::Descubierta(_) | CeldaParcial::Pared => Some(false),
+                CeldaParcial::Mina => Some(true),
+            })
+            .collect()
+    }
+
+    /// Retorna una [`Restriccion`] por cada espacio descubierto, sobre sus celdas adyacentes.
+    pub(crate) fn restricciones(&self) -> Vec<Restriccion> {
+        self.celdas
+            .iter()
+            .enumerate()
+            .filter_map(|(i, c)| match c {
+                CeldaParcial::Descubierta(n) => {
+                    Some(Restriccion::new(self.indices_adyacentes(i), *n as usize))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Aplica una vez las reglas de deducción sobre cada restricción por separado, actualizando las celdas `conocidas`. Retorna `true` si se obtuvo información nueva.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`PistasInconsistentes`][ErrorMapa::PistasInconsistentes] si alguna restricción no puede cumplirse.
+    fn aplicar_reglas(
+        restricciones: &[Restriccion],
+        conocidas: &mut [Option<bool>],
+    ) -> Result<bool, ErrorMapa> {
+        let mut cambios = false;
+        for restriccion in restricciones {
+            let reducida = restriccion.reducir(conocidas)?;
+            if let Some(es_mina) = reducida.deducir() {
+                cambios |= Self::asignar(conocidas, reducida.celdas(), es_mina);
+            }
+        }
+        Ok(cambios)
+    }
+
+    /// Aplica una vez la regla de [comparación](Restriccion::comparar) entre cada par de restricciones que comparten celdas desconocidas, actualizando las celdas `conocidas`. Retorna `true` si se obtuvo información nueva.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`PistasInconsistentes`][ErrorMapa::PistasInconsistentes] si alguna restricción no puede cumplirse.
+    fn aplicar_reglas_de_pares(
+        restricciones: &[Restriccion],
+        conocidas: &mut [Option<bool>],
+    ) -> Result<bool, ErrorMapa> {
+        let reducidas = Self::reducir_todas(restricciones, conocidas)?;
+        let por_celda = Self::restricciones_por_celda(&reducidas, conocidas.len());
+        let mut cambios = false;
+        for restriccion in &reducidas {
+            let mut solapadas: Vec<usize> = restriccion
+                .celdas()
+                .iter()
+                .flat_map(|c| por_celda[*c].iter().copied())
+                .collect();
+            solapadas.sort_unstable();
+            solapadas.dedup();
+            for otra in solapadas {
+                if let Some((minas, seguras)) = restriccion.comparar(&reducidas[otra]) {
+                    cambios |= Self::asignar(conocidas, &minas, true);
+                    cambios |= Self::asignar(conocidas, &seguras, false);
+                }
+            }
+        }
+        Ok(cambios)
+    }
+
+    /// Retorna las restricciones reducidas a las celdas todavía desconocidas, descartando las que ya no involucran ninguna celda.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`PistasInconsistentes`][ErrorMapa::PistasInconsistentes] si alguna restricción no puede cumplirse.
+    fn reducir_todas(
+        restricciones: &[Restriccion],
+        conocidas: &[Option<bool>],
+    ) -> Result<Vec<Restriccion>, ErrorMapa> {
+        let mut reducidas = Vec::with_capacity(restricciones.len());
+        for restriccion in restricciones {
+            let reducida = restriccion.reducir(conocidas)?;
+            if !reducida.celdas().is_empty() {
+                reducidas.push(reducida);
+            }
+        }
+        Ok(reducidas)
+    }
+
+    /// Retorna, para cada celda del tablero, los indices de las `restricciones` que la involucran.
+    fn restricciones_por_celda(restricciones: &[Restriccion], celdas: usize) -> Vec<Vec<usize>> {
+        let mut por_celda = vec![vec![]; celdas];
+        for (i, restriccion) in restricciones.iter().enumerate() {
+            for &celda in restriccion.celdas() {
+                por_celda[celda].push(i);
+            }
+        }
+        por_celda
+    }
+
+    /// Asigna a las `celdas` todavía desconocidas si son minas o no. Retorna `true` si alguna celda fue asignada.
+    fn asignar(conocidas: &mut [Option<bool>], celdas: &[usize], es_mina: bool) -> bool {
+        let mut cambios = false;
+        for &celda in celdas {
+            if conocidas[celda].is_none() {
+                conocidas[celda] = Some(es_mina);
+                cambios = true;
+            }
+        }
+        cambios
+    }
+
+    /// Construye la [`Deduccion`] a partir de las celdas que eran desconocidas y ahora son `conocidas`.
+    fn construir_deduccion(&self, conocidas: &[Option<bool>]) -> Deduccion<N> {
+        let (mut minas, mut seguras) = (vec![], vec![]);
+        for (i, conocida) in conocidas.iter().enumerate() {
+            if self.celdas[i] != CeldaParcial::Desconocida {
+                continue;
+            }
+            match conocida {
+                Some(true) => minas.push(self.obtener_coordenada(i)),
+                Some(false) => seguras.push(self.obtener_coordenada(i)),
+                None => (),
+            }
+        }
+        Deduccion::new(minas, seguras)
+    }
+
+    /// Retorna la [coordenada][Coordenadas] correspondiente a un indice dentro de las celdas.
+    pub(crate) fn obtener_coordenada(&self, indice: usize) -> Coordenadas<N> {
+        Coordenadas::desde_indice(indice, self.dimensiones)
+    }
+
+    /// Retorna los indices de todas las celdas adyacentes a la celda del indice dado.
+    fn indices_adyacentes(&self, indice: usize) -> Vec<usize> {
+        let coordenada = self.obtener_coordenada(indice);
+        self.vecindario
+            .coordenadas_adyacentes(&coordenada, self.dimensiones, self.topologia)
+            .iter()
+            .filter_map(|c| c.indice(self.dimensiones))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::tablero::builder::TableroBuilder;
+    use crate::tablero::coordenada::{Coordenadas2D, Coordenadas3D};
+    use crate::tablero::partida::Partida;
+    use CeldaParcial::{Desconocida, Descubierta};
+
+    /// Construye el [`TableroParcial`] de los `casilleros` con la configuración por defecto del [`TableroBuilder`]. Se comparte con las pruebas de los módulos que razonan sobre tableros parciales.
+    pub(crate) fn crear_parcial(casilleros: &str) -> TableroParcial {
+        TableroBuilder::new(casilleros)
+            .unwrap()
+            .crear_tablero_parcial()
+            .unwrap()
+    }
+
+    #[test]
+    fn deducir_celdas_seguras() {
+        // ? 1 ? .
+        let parcial = TableroParcial::new(
+            [4, 1],
+            Topologia::Acotada,
+            Vecindario::Moore,
+            Simbolos::default(),
+            vec![Desconocida, Descubierta(1), Desconocida, Descubierta(0)],
+        );
+        let resultado = parcial.deducir().unwrap();
+        assert_eq!(resultado.seguras(), &[Coordenadas2D::new(2, 0)]);
+        assert_eq!(resultado.minas(), &[Coordenadas2D::new(0, 0)]);
+    }
+
+    #[test]
+    fn deducir_encadenando_reglas() {
+        // 1 ? ?
+        // 1 ? ?
+        // . . ?
+        let parcial = TableroParcial::new(
+            [3, 3],
+            Topologia::Acotada,
+            Vecindario::Moore,
+            Simbolos::default(),
+            vec![
+                Descubierta(1),
+                Desconocida,
+                Desconocida,
+                Descubierta(1),
+                Desconocida,
+                Desconocida,
+                Descubierta(0),
+                Descubierta(0),
+                Desconocida,
+            ],
+        );
+        let resultado = parcial.deducir().unwrap();
+        assert_eq!(resultado.minas(), &[Coordenadas2D::new(1, 0)]);
+        let seguras = [
+            Coordenadas2D::new(1, 1),
+            Coordenadas2D::new(2, 1),
+            Coordenadas2D::new(2, 2),
+        ];
+        assert_eq!(resultado.seguras(), &seguras);
+    }
+
+    #[test]
+    fn deducir_sin_informacion_suficiente() {
+        // 1 ?
+        // ? ?
+        let parcial = TableroParcial::new(
+            [2, 2],
+            Topologia::Acotada,
+            Vecindario::Moore,
+            Simbolos::default(),
+            vec![Descubierta(1), Desconocida, Desconocida, Desconocida],
+        );
+        let resultado = parcial.deducir().unwrap();
+        assert!(resultado.seguras().is_empty());
+        assert!(resultado.minas().is_empty());
+    }
+
+    #[test]
+    fn deducir_con_regla_de_subconjuntos() {
+        // ? ? ?
+        // 1 1 1
+        let parcial = crear_parcial("???\n111\n");
+        let resultado = parcial.deducir().unwrap();
+        let seguras = [Coordenadas2D::new(0, 0), Coordenadas2D::new(2, 0)];
+        assert_eq!(resultado.seguras(), &seguras);
+        assert_eq!(resultado.minas(), &[Coordenadas2D::new(1, 0)]);
+    }
+
+    #[test]
+    fn deducir_con_patron_1_2() {
+        // ? ? ? ? ?
+        // 1 2 2 1 .
+        let parcial = crear_parcial("?????\n1221.\n");
+        let resultado = parcial.deducir().unwrap();
+        let minas = [Coordenadas2D::new(1, 0), Coordenadas2D::new(2, 0)];
+        let seguras = [
+            Coordenadas2D::new(0, 0),
+            Coordenadas2D::new(3, 0),
+            Coordenadas2D::new(4, 0),
+        ];
+        assert_eq!(resultado.minas(), &minas);
+        assert_eq!(resultado.seguras(), &seguras);
+    }
+
+    #[test]
+    fn deducir_con_minas_conocidas() {
+        // * 2 ?
+        // ? ? ?
+        let parcial = crear_parcial("*2?\n???\n");
+        let resultado = parcial.deducir().unwrap();
+        assert!(resultado.seguras().is_empty());
+        assert!(resultado.minas().is_empty());
+
+        let parcial = crear_parcial("*1?\n???\n");
+        let resultado = parcial.deducir().unwrap();
+        assert_eq!(resultado.seguras().len(), 4);
+    }
+
+    #[test]
+    fn deducir_pistas_inconsistentes() {
+        let parcial = crear_parcial("?3?\n...\n");
+        let resultado = parcial.deducir();
+        assert_eq!(resultado, Err(ErrorMapa::PistasInconsistentes));
+
+        let parcial = crear_parcial("1.1\n...\n");
+        let resultado = parcial.deducir();
+        assert_eq!(resultado, Err(ErrorMapa::PistasInconsistentes));
+    }
+
+    #[test]
+    fn mostrar_tablero_parcial() {
+        let parcial = crear_parcial("*2?\n.1?\n");
+        assert_eq!(parcial.to_string(), "*2?\n.1?\n");
+    }
+
+    #[test]
+    fn deducir_en_tablero_toroidal() {
+        // 1 . ? ?
+        let builder = TableroBuilder::new("1.??\n").unwrap();
+        let resultado = builder.crear_tablero_parcial().unwrap().deducir();
+        assert_eq!(resultado, Err(ErrorMapa::PistasInconsistentes));
+
+        let builder = builder.con_topologia(Topologia::Toroidal);
+        let resultado = builder.crear_tablero_parcial().unwrap().deducir().unwrap();
+        assert_eq!(resultado.minas(), &[Coordenadas2D::new(3, 0)]);
+        assert_eq!(resultado.seguras(), &[Coordenadas2D::new(2, 0)]);
+    }
+
+    #[test]
+    fn deducir_en_tablero_hexagonal() {
+        // ? ? ?
+        //  2 . .
+        // ? ? ?
+        let builder = TableroBuilder::new("? ? ?\n 2 . .\n? ? ?\n").unwrap();
+        let parcial = builder
+            .con_vecindario(Vecindario::Hexagonal)
+            .crear_tablero_parcial()
+            .unwrap();
+        assert_eq!(parcial.to_string(), "? ? ?\n 2 . .\n? ? ?\n");
+        let resultado = parcial.deducir().unwrap();
+        assert_eq!(
+            resultado.minas(),
+            &[Coordenadas2D::new(0, 0), Coordenadas2D::new(0, 2)]
+        );
+        assert_eq!(resultado.seguras().len(), 4);
+    }
+
+    #[test]
+    fn deducir_con_vecindario_de_von_neumann() {
+        // ? ? ?
+        // 1 . ?
+        let builder = TableroBuilder::new("???\n1.?\n").unwrap();
+        let parcial = builder
+            .con_vecindario(Vecindario::VonNeumann)
+            .crear_tablero_parcial()
+            .unwrap();
+        let resultado = parcial.deducir().unwrap();
+        assert_eq!(resultado.minas(), &[Coordenadas2D::new(0, 0)]);
+        assert_eq!(
+            resultado.seguras(),
+            &[Coordenadas2D::new(1, 0), Coordenadas2D::new(2, 1)]
+        );
+    }
+
+    #[test]
+    fn paredes_no_contienen_minas() {
+        // ? 1
+        // # #
+        let parcial = crear_parcial("?1\n##\n");
+        let resultado = parcial.deducir().unwrap();
+        assert_eq!(resultado.minas(), &[Coordenadas2D::new(0, 0)]);
+        let esperado = TableroBuilder::new("*.\n##\n")
+            .unwrap()
+            .crear_tablero()
+            .unwrap();
+        assert_eq!(parcial.reconstruir(), Ok(Reconstruccion::Unica(esperado)));
+        assert_eq!(parcial.to_string(), "?1\n##\n");
+    }
+
+    #[test]
+    fn deducir_en_tres_dimensiones() {
+        let mut casilleros = "***\n***\n***\n\n".repeat(3);
+        casilleros.replace_range(18..19, ".");
+        let tablero = TableroBuilder::new(&casilleros)
+            .unwrap()
+            .crear_tablero_3d()
+            .unwrap();
+        let mut partida = Partida::new(tablero);
+        partida.revelar(Coordenadas3D::new(1, 1, 1)).unwrap();
+        let parcial = partida.vista().unwrap();
+        assert_eq!(parcial.dimensiones(), [3, 3, 3]);
+        assert_eq!(parcial.deducir().unwrap().minas().len(), 26);
+        assert_eq!(
+            parcial.verificar_unicidad(26).map(|u| u.es_unica()),
+            Ok(true)
+        );
+        let capa = " ?  ?  ?\n ?  ?  ?\n ?  ?  ?\n";
+        let centro = " ?  ?  ?\n ? 26  ?\n ?  ?  ?\n";
+        assert_eq!(parcial.to_string(), format!("{capa}\n{centro}\n{capa}"));
+    }
+}
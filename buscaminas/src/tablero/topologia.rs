@@ -0,0 +1,92 @@
+//! # Topologia
+//! `topologia` es un submódulo que contiene a [`Topologia`], la forma en la que se unen los bordes de un [`Tablero`].
+//!
+//! [`Tablero`]: ../struct.Tablero.html
+use crate::tablero::coordenada::Coordenadas;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// `enum` cuyas variantes representan las formas en que pueden tratarse los bordes del [`Tablero`](../struct.Tablero.html). Determina qué celdas son adyacentes y, por lo tanto, afecta al recuento de minas, a la expansión de regiones sin minas y a la deducción.
+pub enum Topologia {
+    /// Variante que representa al tablero clásico, limitado por sus bordes.
+    #[default]
+    Acotada,
+    /// Variante que representa a un tablero con forma de toro, en el que los bordes opuestos están unidos. Por ejemplo, la celda `(0, 0)` es adyacente a la celda `(ancho - 1, largo - 1)`. En tableros de otra cantidad de dimensiones se unen los bordes opuestos de cada una de ellas.
+    Toroidal,
+}
+
+impl Topologia {
+    /// Retorna las [coordenadas][Coordenadas] adyacentes a la `coordenada` dada en un tablero de las `dimensiones` indicadas con esta topología.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::coordenada::Coordenadas2D;
+    /// # use buscaminas::tablero::topologia::Topologia;
+    /// #
+    /// # fn main() {
+    /// let esquina = Coordenadas2D::new(0, 0);
+    /// let opuesta = Coordenadas2D::new(3, 3);
+    /// let acotadas = Topologia::Acotada.coordenadas_adyacentes(&esquina, [4, 4]);
+    /// let toroidales = Topologia::Toroidal.coordenadas_adyacentes(&esquina, [4, 4]);
+    /// assert!(!acotadas.contains(&opuesta));
+    /// assert!(toroidales.contains(&opuesta));
+    /// # }
+    /// ```
+    pub fn coordenadas_adyacentes<const N: usize>(
+        &self,
+        coordenada: &Coordenadas<N>,
+        dimensiones: [usize; N],
+    ) -> Vec<Coordenadas<N>> {
+        match self {
+            Topologia::Acotada => coordenada.coordenadas_adyacentes(dimensiones),
+            Topologia::Toroidal => coordenada.coordenadas_adyacentes_toroidales(dimensiones),
+        }
+    }
+
+    /// Retorna la [coordenada][Coordenadas] que se obtiene al sumar el `desplazamiento` de cada dimensión a la `coordenada` dada en un tablero de las `dimensiones` indicadas, o `None` si el desplazamiento sale del tablero.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::coordenada::Coordenadas2D;
+    /// # use buscaminas::tablero::topologia::Topologia;
+    /// #
+    /// # fn main() {
+    /// let origen = Coordenadas2D::new(0, 0);
+    /// assert_eq!(Topologia::Acotada.desplazar(&origen, [-1, 0], [4, 4]), None);
+    /// assert_eq!(Topologia::Toroidal.desplazar(&origen, [-1, 0], [4, 4]), Some(Coordenadas2D::new(3, 0)));
+    /// # }
+    /// ```
+    pub fn desplazar<const N: usize>(
+        &self,
+        coordenada: &Coordenadas<N>,
+        desplazamiento: [isize; N],
+        dimensiones: [usize; N],
+    ) -> Option<Coordenadas<N>> {
+        let mut posiciones = coordenada.posiciones();
+        for (dimension, posicion) in posiciones.iter_mut().enumerate() {
+            *posicion = self.desplazar_en_dimension(
+                *posicion,
+                desplazamiento[dimension],
+                dimensiones[dimension],
+            )?;
+        }
+        Some(Coordenadas::from(posiciones))
+    }
+
+    /// Desplaza la `posicion` en una de las dimensiones del tablero.
+    fn desplazar_en_dimension(
+        &self,
+        posicion: usize,
+        desplazamiento: isize,
+        dimension: usize,
+    ) -> Option<usize> {
+        match self {
+            Topologia::Acotada => posicion
+                .checked_add_signed(desplazamiento)
+                .filter(|p| *p < dimension),
+            Topologia::Toroidal => {
+                let dimension = dimension as isize;
+                Some((posicion as isize + desplazamiento).rem_euclid(dimension) as usize)
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! # Unicidad
+//! `unicidad` es un submódulo que contiene a [`Unicidad`], el resultado de verificar si un problema del buscaminas tiene una única solución.
+use crate::error::error_mapa::ErrorMapa;
+use crate::tablero::casillero::Casillero;
+use crate::tablero::coordenada::Coordenadas;
+use crate::tablero::reconstruccion::Reconstruccion;
+use crate::tablero::tablero_parcial::TableroParcial;
+use crate::tablero::Tablero;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// `enum` cuyas variantes indican si un problema, formado por un [`TableroParcial`] y la cantidad total de minas, tiene solución y si esta es única.
+pub enum Unicidad<const N: usize = 2> {
+    /// Variante que indica que ninguna distribución de minas es compatible con el problema.
+    SinSolucion,
+    /// Variante que indica que el problema tiene una única solución.
+    Unica(Tablero<N>),
+    /// Variante que indica que el problema tiene más de una solución. Contiene dos de ellas como testigos.
+    Ambigua(Tablero<N>, Tablero<N>),
+    /// Variante que indica que no pudo decidirse si el problema tiene solución única, porque admite demasiadas distribuciones de minas para analizarlas dentro de la cantidad máxima de pasos.
+    Indeterminada,
+}
+
+impl<const N: usize> fmt::Display for Unicidad<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Unicidad::SinSolucion => writeln!(f, "El problema no tiene solución."),
+            Unicidad::Unica(solucion) => {
+                writeln!(f, "El problema tiene solución única:")?;
+                write!(f, "{}", solucion)
+            }
+            Unicidad::Ambigua(primera, segunda) => {
+                write!(f, "El problema tiene más de una solución. Difieren en:")?;
+                for coordenada in self.diferencias() {
+                    let posiciones = coordenada.posiciones().map(|p| p.to_string());
+                    write!(f, " ({})", posiciones.join(", "))?;
+                }
+                write!(f, "\n\n{}\n{}", primera, segunda)
+            }
+            Unicidad::Indeterminada => writeln!(
+                f,
+                "No se pudo determinar si el problema tiene solución única: admite demasiadas distribuciones de minas."
+            ),
+        }
+    }
+}
+
+impl<const N: usize> Unicidad<N> {
+    /// Verifica si el tablero `parcial` tiene una única solución con exactamente `minas_totales` minas. Si la búsqueda de soluciones se [excede][ErrorMapa::BusquedaExcedida], el problema queda [indeterminado][Unicidad::Indeterminada].
+    ///
+    /// # Errores
+    ///
+    /// Retornara el error dado al [reconstruir][Reconstruccion] las soluciones.
+    pub(crate) fn verificar(
+        parcial: &TableroParcial<N>,
+        minas_totales: usize,
+    ) -> Result<Unicidad<N>, ErrorMapa> {
+        let reconstruccion = match Reconstruccion::calcular(parcial, Some(minas_totales), 2) {
+            Err(ErrorMapa::BusquedaExcedida) => return Ok(Unicidad::Indeterminada),
+            resultado => resultado?,
+        };
+        let soluciones = match reconstruccion {
+            Reconstruccion::Ninguna => return Ok(Unicidad::SinSolucion),
+            Reconstruccion::Unica(solucion) => return Ok(Unicidad::Unica(solucion)),
+            Reconstruccion::Multiple(soluciones) => soluciones,
+        };
+        let mut soluciones = soluciones.into_iter();
+        match (soluciones.next(), soluciones.next()) {
+            (Some(primera), Some(segunda)) => Ok(Unicidad::Ambigua(primera, segunda)),
+            _ => Ok(Unicidad::SinSolucion),
+        }
+    }
+
+    /// Retorna `true` si el problema tiene una única solución.
+    pub fn es_unica(&self) -> bool {
+        matches!(self, Unicidad::Unica(_))
+    }
+
+    /// Retorna las [coordenadas][Coordenadas] de las celdas que son minas en uno de los testigos y no en el otro. Si el problema no es ambiguo, no hay diferencias.
+    pub fn diferencias(&self) -> Vec<Coordenadas<N>> {
+        let (primera, segunda) = match self {
+            Unicidad::Ambigua(primera, segunda) => (primera, segunda),
+            _ => return vec![],
+        };
+        let es_mina = |c: &Casillero| matches!(c, Casillero::Mina(_));
+        (0..primera.mapa.len())
+            .filter(|&i| es_mina(&primera.mapa[i]) != es_mina(&segunda.mapa[i]))
+            .map(|i| primera.obtener_coordenada(i))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tablero::builder::TableroBuilder;
+    use crate::tablero::coordenada::Coordenadas2D;
+    use crate::tablero::tablero_parcial::tests::crear_parcial;
+
+    #[test]
+    fn problema_con_solucion_unica() {
+        // ? ? ?
+        // 1 2 1
+        let parcial = crear_parcial("???\n121\n");
+        let resultado = Unicidad::verificar(&parcial, 2).unwrap();
+        let esperado = TableroBuilder::new("*.*\n...\n")
+            .unwrap()
+            .crear_tablero()
+            .unwrap();
+        assert_eq!(resultado, Unicidad::Unica(esperado));
+        assert!(resultado.diferencias().is_empty());
+    }
+
+    #[test]
+    fn problema_ambiguo() {
+        // ? 1
+        // 1 ?
+        let parcial = crear_parcial("?1\n1?\n");
+        let resultado = Unicidad::verificar(&parcial, 1).unwrap();
+        assert!(!resultado.es_unica());
+        let diferencias = vec![Coordenadas2D::new(0, 0), Coordenadas2D::new(1, 1)];
+        assert_eq!(resultado.diferencias(), diferencias);
+    }
+
+    #[test]
+    fn cantidad_de_minas_restringe_soluciones() {
+        // ? ? ?
+        // ? 1 ?
+        // . . .
+        let parcial = crear_parcial("???\n?1?\n...\n");
+        assert!(!Unicidad::verificar(&parcial, 1).unwrap().es_unica());
+        assert_eq!(Unicidad::verificar(&parcial, 2), Ok(Unicidad::SinSolucion));
+
+        let parcial = crear_parcial("1??\n");
+        assert!(Unicidad::verificar(&parcial, 1).unwrap().es_unica());
+        assert!(Unicidad::verificar(&parcial, 2).unwrap().es_unica());
+        assert_eq!(Unicidad::verificar(&parcial, 3), Ok(Unicidad::SinSolucion));
+    }
+
+    #[test]
+    fn mostrar_problema_ambiguo() {
+        let parcial = crear_parcial("?1\n1?\n");
+        let resultado = Unicidad::verificar(&parcial, 1).unwrap();
+        let esperado = "El problema tiene más de una solución. Difieren en: (0, 0) (1, 1)\n\n11\n1*\n\n*1\n11\n";
+        assert_eq!(resultado.to_string(), esperado);
+    }
+
+    #[test]
+    fn problema_indeterminado() {
+        // ? ? ? ? ...
+        // 1 ? 1 ? ...
+        // ? ? ? ? ...
+        let fila = "?".repeat(32);
+        let casilleros = format!("{}\n{}\n{}\n", fila, "1?".repeat(16), fila);
+        let resultado = Unicidad::verificar(&crear_parcial(&casilleros), 20).unwrap();
+        assert_eq!(resultado, Unicidad::Indeterminada);
+        assert!(!resultado.es_unica());
+        assert!(resultado.diferencias().is_empty());
+    }
+}
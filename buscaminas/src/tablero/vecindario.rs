@@ -0,0 +1,366 @@
+//! # Vecindario
+//! `vecindario` es un submódulo que contiene a [`Vecindario`], la regla que determina qué celdas de un [`Tablero`] son adyacentes entre sí.
+//!
+//! [`Tablero`]: ../struct.Tablero.html
+use crate::error::error_mapa::ErrorMapa;
+use crate::tablero::coordenada::Coordenadas;
+use crate::tablero::topologia::Topologia;
+
+/// Desplazamientos a las celdas alcanzables con un movimiento del caballo del ajedrez.
+const CABALLO: [(isize, isize); 8] = [
+    (-2, -1),
+    (-2, 1),
+    (-1, -2),
+    (-1, 2),
+    (1, -2),
+    (1, 2),
+    (2, -1),
+    (2, 1),
+];
+/// Desplazamientos a las celdas adyacentes de una celda de una fila par en un tablero hexagonal.
+const HEXAGONAL_FILA_PAR: [(isize, isize); 6] =
+    [(-1, -1), (0, -1), (-1, 0), (1, 0), (-1, 1), (0, 1)];
+/// Desplazamientos a las celdas adyacentes de una celda de una fila impar en un tablero hexagonal.
+const HEXAGONAL_FILA_IMPAR: [(isize, isize); 6] =
+    [(0, -1), (1, -1), (-1, 0), (1, 0), (0, 1), (1, 1)];
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+/// `enum` cuyas variantes representan las formas de definir las celdas adyacentes a una celda del [`Tablero`](../struct.Tablero.html).
+///
+/// Los vecindarios de [Moore][Vecindario::Moore] y de [Von Neumann][Vecindario::VonNeumann] se definen para cualquier cantidad de dimensiones. El resto se define sobre el plano: en tableros de tres dimensiones sus desplazamientos se aplican dentro de cada capa y en tableros de una dimensión solo se consideran los que no cambian de fila.
+pub enum Vecindario {
+    /// Variante que representa al vecindario clásico: las ocho celdas que rodean a la celda en dirección recta o diagonal (26 en un tablero de tres dimensiones).
+    #[default]
+    Moore,
+    /// Variante que representa al vecindario de Von Neumann: solo las cuatro celdas que se encuentran en dirección recta (seis en un tablero de tres dimensiones).
+    VonNeumann,
+    /// Variante en la que son adyacentes las ocho celdas alcanzables con un movimiento del caballo del ajedrez.
+    Caballo,
+    /// Variante que representa a un tablero de celdas hexagonales, cada una con seis celdas adyacentes.
+    ///
+    /// Las filas impares se encuentran desplazadas media celda hacia la derecha, por lo que una celda es adyacente a las dos celdas de su misma fila, a dos de la fila anterior y a dos de la fila siguiente:
+    ///
+    /// ```txt
+    ///  . a b .
+    /// . c x d
+    ///  . e f .
+    /// ```
+    Hexagonal,
+    /// Variante definida por una lista de desplazamientos `(dx, dy)` que, sumados a una celda, indican sus celdas adyacentes. El desplazamiento `(0, 0)` y los repetidos se ignoran.
+    ///
+    /// Para que las deducciones sobre un [`TableroParcial`](../tablero_parcial/struct.TableroParcial.html) sean correctas, los desplazamientos deben ser simétricos: si `(dx, dy)` pertenece a la lista, `(-dx, -dy)` también.
+    Personalizado(Vec<(isize, isize)>),
+}
+
+impl Vecindario {
+    /// Retorna las [coordenadas][Coordenadas] adyacentes a la `coordenada` dada en un tablero de las `dimensiones` indicadas con la [`Topologia`] dada.
+    ///
+    /// # Ejemplos
+    /// ```
+    /// # use buscaminas::tablero::coordenada::Coordenadas2D;
+    /// # use buscaminas::tablero::topologia::Topologia;
+    /// # use buscaminas::tablero::vecindario::Vecindario;
+    /// #
+    /// # fn main() {
+    /// let coordenada = Coordenadas2D::new(1, 1);
+    /// let moore = Vecindario::Moore.coordenadas_adyacentes(&coordenada, [3, 3], Topologia::Acotada);
+    /// let hexagonal = Vecindario::Hexagonal.coordenadas_adyacentes(&coordenada, [3, 3], Topologia::Acotada);
+    /// assert_eq!(moore.len(), 8);
+    /// assert_eq!(hexagonal.len(), 6);
+    /// # }
+    /// ```
+    pub fn coordenadas_adyacentes<const N: usize>(
+        &self,
+        coordenada: &Coordenadas<N>,
+        dimensiones: [usize; N],
+        topologia: Topologia,
+    ) -> Vec<Coordenadas<N>> {
+        let desplazamientos = match self {
+            Vecindario::Moore => return topologia.coordenadas_adyacentes(coordenada, dimensiones),
+            Vecindario::VonNeumann => Self::desplazamientos_von_neumann(),
+            _ => self.desplazamientos_en_el_plano(coordenada),
+        };
+        let mut coordenadas = Vec::with_capacity(desplazamientos.len());
+        for desplazamiento in desplazamientos {
+            match topologia.desplazar(coordenada, desplazamiento, dimensiones) {
+                Some(c) if c != *coordenada && !coordenadas.contains(&c) => coordenadas.push(c),
+                _ => (),
+            }
+        }
+        coordenadas
+    }
+
+    /// Retorna los desplazamientos a las celdas adyacentes en el vecindario de Von Neumann de `N` dimensiones: un paso hacia atrás o hacia adelante en una única dimensión.
+    fn desplazamientos_von_neumann<const N: usize>() -> Vec<[isize; N]> {
+        let paso = |dimension: usize, valor: isize| {
+            let mut desplazamiento = [0; N];
+            desplazamiento[dimension] = valor;
+            desplazamiento
+        };
+        let atras = (0..N).map(|d| paso(d, -1));
+        let adelante = (0..N).rev().map(|d| paso(d, 1));
+        atras.chain(adelante).collect()
+    }
+
+    /// Retorna los desplazamientos `(dx, dy)` de los vecindarios definidos sobre el plano, extendidos a `N` dimensiones. Los que no pueden representarse en un tablero de una dimensión se descartan.
+    fn desplazamientos_en_el_plano<const N: usize>(
+        &self,
+        coordenada: &Coordenadas<N>,
+    ) -> Vec<[isize; N]> {
+        let fila = coordenada.posiciones().get(1).copied().unwrap_or(0);
+        let desplazamientos: &[(isize, isize)] = match self {
+            Vecindario::Hexagonal if fila.is_multiple_of(2) => &HEXAGONAL_FILA_PAR,
+            Vecindario::Hexagonal => &HEXAGONAL_FILA_IMPAR,
+            Vecindario::Caballo => &CABALLO,
+            Vecindario::Personalizado(desplazamientos) => desplazamientos,
+            Vecindario::Moore | Vecindario::VonNeumann => &[],
+        };
+        desplazamientos
+            .iter()
+            .filter_map(|&(dx, dy)| {
+                let mut desplazamiento = [0; N];
+                for (posicion, valor) in desplazamiento.iter_mut().zip([dx, dy]) {
+                    *posicion = valor;
+                }
+                (N >= 2 || dy == 0).then_some(desplazamiento)
+            })
+            .collect()
+    }
+
+    /// Verifica que el vecindario pueda utilizarse en un tablero de las `dimensiones` y la `topologia` dadas. En un tablero [hexagonal][Vecindario::Hexagonal] y [toroidal][Topologia::Toroidal] la cantidad de filas debe ser par, ya que el desplazamiento de las filas impares no se conserva al pasar de la última fila a la primera y la adyacencia dejaría de ser simétrica.
+    ///
+    /// # Errores
+    ///
+    /// Retorna [`MapaMalformado`][ErrorMapa::MapaMalformado] si el tablero es hexagonal, toroidal y tiene una cantidad impar de filas.
+    pub(crate) fn verificar<const N: usize>(
+        &self,
+        topologia: Topologia,
+        dimensiones: [usize; N],
+    ) -> Result<(), ErrorMapa> {
+        let filas = dimensiones.get(1).copied().unwrap_or(1);
+        match (self, topologia) {
+            (Vecindario::Hexagonal, Topologia::Toroidal) if !filas.is_multiple_of(2) => {
+                Err(ErrorMapa::MapaMalformado)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Retorna la mayor distancia entre las filas de una celda y de sus adyacentes, es decir, cuántas filas hacia arriba y hacia abajo es necesario conocer para contar sus minas.
+    pub(crate) fn alcance(&self) -> usize {
+        match self {
+            Vecindario::Moore | Vecindario::VonNeumann | Vecindario::Hexagonal => 1,
+            Vecindario::Caballo => 2,
+            Vecindario::Personalizado(desplazamientos) => desplazamientos
+                .iter()
+                .map(|(_, dy)| dy.unsigned_abs())
+                .max()
+                .unwrap_or(0),
+        }
+    }
+
+    /// Retorna la cantidad de celdas adyacentes a una celda de un tablero de dos dimensiones alejada de los bordes, es decir, la mayor cantidad de celdas cuyas minas suman a su recuento.
+    pub(crate) fn tamanio(&self) -> usize {
+        match self {
+            Vecindario::Moore => 8,
+            Vecindario::VonNeumann => 4,
+            Vecindario::Hexagonal => HEXAGONAL_FILA_PAR.len(),
+            Vecindario::Caballo => CABALLO.len(),
+            Vecindario::Personalizado(desplazamientos) => {
+                let mut distintos: Vec<_> =
+                    desplazamientos.iter().filter(|d| **d != (0, 0)).collect();
+                distintos.sort();
+                distintos.dedup();
+                distintos.len()
+            }
+        }
+    }
+
+    /// Retorna el texto que se escribe antes de la celda del `indice` dado al mostrar un tablero de `ancho` columnas. En los tableros hexagonales las celdas se separan con un espacio y las filas impares comienzan con un espacio, de modo que se aprecie el desplazamiento.
+    pub(crate) fn prefijo(&self, indice: usize, ancho: usize) -> &'static str {
+        match self {
+            Vecindario::Hexagonal if !indice.is_multiple_of(ancho) => " ",
+            Vecindario::Hexagonal if !(indice / ancho).is_multiple_of(2) => " ",
+            _ => "",
+        }
+    }
+
+    /// Retorna `true` si el `caracter` debe ignorarse al construir un tablero con este vecindario, por tratarse de un separador del [formato de texto][Vecindario::prefijo].
+    pub(crate) fn es_separador(&self, caracter: char) -> bool {
+        *self == Vecindario::Hexagonal && caracter == ' '
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tablero::coordenada::{Coordenadas2D, Coordenadas3D};
+
+    #[test]
+    fn adyacentes_hexagonales() {
+        let vecindario = Vecindario::Hexagonal;
+        let resultado = vecindario.coordenadas_adyacentes(
+            &Coordenadas2D::new(1, 1),
+            [3, 3],
+            Topologia::Acotada,
+        );
+        let esperado = [
+            Coordenadas2D::new(1, 0),
+            Coordenadas2D::new(2, 0),
+            Coordenadas2D::new(0, 1),
+            Coordenadas2D::new(2, 1),
+            Coordenadas2D::new(1, 2),
+            Coordenadas2D::new(2, 2),
+        ];
+        assert_eq!(resultado, esperado);
+
+        let resultado = vecindario.coordenadas_adyacentes(
+            &Coordenadas2D::new(0, 0),
+            [3, 3],
+            Topologia::Acotada,
+        );
+        assert_eq!(
+            resultado,
+            [Coordenadas2D::new(1, 0), Coordenadas2D::new(0, 1)]
+        );
+    }
+
+    #[test]
+    fn adyacentes_hexagonales_toroidales() {
+        let vecindario = Vecindario::Hexagonal;
+        let resultado = vecindario.coordenadas_adyacentes(
+            &Coordenadas2D::new(0, 0),
+            [4, 4],
+            Topologia::Toroidal,
+        );
+        let esperado = [
+            Coordenadas2D::new(3, 3),
+            Coordenadas2D::new(0, 3),
+            Coordenadas2D::new(3, 0),
+            Coordenadas2D::new(1, 0),
+            Coordenadas2D::new(3, 1),
+            Coordenadas2D::new(0, 1),
+        ];
+        assert_eq!(resultado, esperado);
+    }
+
+    #[test]
+    fn adyacencia_hexagonal_toroidal_simetrica() {
+        let dimensiones = [3, 4];
+        let vecindario = Vecindario::Hexagonal;
+        assert!(vecindario
+            .verificar(Topologia::Toroidal, dimensiones)
+            .is_ok());
+        let adyacentes = |c: &Coordenadas2D| {
+            vecindario.coordenadas_adyacentes(c, dimensiones, Topologia::Toroidal)
+        };
+        for (x, y) in (0..3).flat_map(|x| (0..4).map(move |y| (x, y))) {
+            let celda = Coordenadas2D::new(x, y);
+            assert!(adyacentes(&celda)
+                .iter()
+                .all(|a| adyacentes(a).contains(&celda)));
+        }
+        let resultado = vecindario.verificar(Topologia::Toroidal, [3, 3]);
+        assert_eq!(resultado, Err(ErrorMapa::MapaMalformado));
+        assert!(vecindario.verificar(Topologia::Acotada, [3, 3]).is_ok());
+    }
+
+    #[test]
+    fn alcance_de_cada_vecindario() {
+        assert_eq!(Vecindario::Moore.alcance(), 1);
+        assert_eq!(Vecindario::Caballo.alcance(), 2);
+        assert_eq!(
+            Vecindario::Personalizado(vec![(-3, 0), (1, -4)]).alcance(),
+            4
+        );
+        assert_eq!(Vecindario::Personalizado(vec![]).alcance(), 0);
+    }
+
+    #[test]
+    fn tamanio_de_cada_vecindario() {
+        assert_eq!(Vecindario::Moore.tamanio(), 8);
+        assert_eq!(Vecindario::VonNeumann.tamanio(), 4);
+        assert_eq!(Vecindario::Hexagonal.tamanio(), 6);
+        let repetidos = Vecindario::Personalizado(vec![(1, 0), (0, 0), (1, 0), (0, 2)]);
+        assert_eq!(repetidos.tamanio(), 2);
+    }
+
+    #[test]
+    fn prefijo_de_celdas_hexagonales() {
+        let prefijos: Vec<&str> = (0..6)
+            .map(|i| Vecindario::Hexagonal.prefijo(i, 3))
+            .collect();
+        assert_eq!(prefijos, ["", " ", " ", " ", " ", " "]);
+        assert_eq!(Vecindario::Moore.prefijo(4, 3), "");
+    }
+
+    #[test]
+    fn adyacentes_von_neumann() {
+        let vecindario = Vecindario::VonNeumann;
+        let resultado = vecindario.coordenadas_adyacentes(
+            &Coordenadas2D::new(0, 1),
+            [3, 3],
+            Topologia::Acotada,
+        );
+        let esperado = [
+            Coordenadas2D::new(0, 0),
+            Coordenadas2D::new(0, 2),
+            Coordenadas2D::new(1, 1),
+        ];
+        assert_eq!(resultado, esperado);
+    }
+
+    #[test]
+    fn adyacentes_caballo() {
+        let vecindario = Vecindario::Caballo;
+        let resultado = vecindario.coordenadas_adyacentes(
+            &Coordenadas2D::new(0, 0),
+            [3, 3],
+            Topologia::Acotada,
+        );
+        assert_eq!(
+            resultado,
+            [Coordenadas2D::new(1, 2), Coordenadas2D::new(2, 1)]
+        );
+
+        let resultado = vecindario.coordenadas_adyacentes(
+            &Coordenadas2D::new(1, 1),
+            [3, 3],
+            Topologia::Acotada,
+        );
+        assert!(resultado.is_empty());
+    }
+
+    #[test]
+    fn adyacentes_personalizados() {
+        let vecindario = Vecindario::Personalizado(vec![(0, 0), (2, 0), (-2, 0), (2, 0)]);
+        let resultado = vecindario.coordenadas_adyacentes(
+            &Coordenadas2D::new(1, 0),
+            [4, 1],
+            Topologia::Acotada,
+        );
+        assert_eq!(resultado, [Coordenadas2D::new(3, 0)]);
+
+        let resultado = vecindario.coordenadas_adyacentes(
+            &Coordenadas2D::new(1, 0),
+            [4, 1],
+            Topologia::Toroidal,
+        );
+        assert_eq!(resultado, [Coordenadas2D::new(3, 0)]);
+    }
+
+    #[test]
+    fn adyacentes_en_otras_dimensiones() {
+        let vecindario = Vecindario::VonNeumann;
+        let coordenada = Coordenadas3D::new(1, 1, 1);
+        let resultado =
+            vecindario.coordenadas_adyacentes(&coordenada, [3, 3, 3], Topologia::Acotada);
+        assert_eq!(resultado.len(), 6);
+        assert!(resultado.contains(&Coordenadas3D::new(1, 1, 0)));
+
+        let vecindario = Vecindario::Personalizado(vec![(2, 0), (0, 1)]);
+        let coordenada = Coordenadas::from([0]);
+        let resultado = vecindario.coordenadas_adyacentes(&coordenada, [3], Topologia::Acotada);
+        assert_eq!(resultado, [Coordenadas::from([2])]);
+    }
+}
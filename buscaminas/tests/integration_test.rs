@@ -1,7 +1,12 @@
 use buscaminas::my_io::escritor::Escritor;
 use buscaminas::my_io::lector::Lector;
 use buscaminas::tablero::builder::TableroBuilder;
+use buscaminas::tablero::coordenada::Coordenadas2D;
+use buscaminas::tablero::vecindario::Vecindario;
 use buscaminas::ARCHIVO_SOLUCION;
+use std::fs;
+use std::io::Write;
+use std::process::{Command, Stdio};
 
 #[test]
 fn resuelve_test1() {
@@ -15,7 +20,7 @@ fn resuelve_test1() {
     let path = "solucion.txt";
     let lector = Lector::new(path);
     let casilleros = lector.leer_archivo().unwrap();
-    assert_eq!(casilleros, "1**22*-13*22*-")
+    assert_eq!(casilleros, "1**22*\n13*22*\n")
 }
 
 #[test]
@@ -30,7 +35,7 @@ fn resuelve_test2() {
     let path = "solucion.txt";
     let lector = Lector::new(path);
     let casilleros = lector.leer_archivo().unwrap();
-    assert_eq!(casilleros, "124*2.-2***2.-2*421.-")
+    assert_eq!(casilleros, "124*2.\n2***2.\n2*421.\n")
 }
 
 #[test]
@@ -61,3 +66,350 @@ fn resuelve_test_no_ascii() {
     let solucion = builder.crear_tablero();
     assert!(solucion.is_err());
 }
+
+#[test]
+fn deduce_test_parcial() {
+    let path = "test_files/test_parcial.txt";
+    let lector = Lector::new(path);
+    let casilleros = lector.leer_archivo().unwrap();
+    let builder = TableroBuilder::new(&casilleros).unwrap();
+    let parcial = builder.crear_tablero_parcial().unwrap();
+    let deduccion = parcial.deducir().unwrap();
+    assert_eq!(
+        deduccion.minas(),
+        &[Coordenadas2D::new(1, 0), Coordenadas2D::new(3, 0)]
+    );
+    assert_eq!(deduccion.seguras().len(), 5);
+}
+
+#[test]
+fn reconstruye_test_numeros() {
+    let path = "test_files/test_numeros.txt";
+    let lector = Lector::new(path);
+    let casilleros = lector.leer_archivo().unwrap();
+    let builder = TableroBuilder::new(&casilleros).unwrap();
+    let parcial = builder.crear_tablero_parcial().unwrap();
+    let reconstruccion = parcial.reconstruir().unwrap();
+    assert!(reconstruccion.es_unica());
+    assert_eq!(
+        reconstruccion.soluciones()[0].to_string(),
+        "1**22*\n13*22*\n"
+    );
+}
+
+#[test]
+fn verifica_unicidad_test_parcial() {
+    let path = "test_files/test_parcial.txt";
+    let lector = Lector::new(path);
+    let casilleros = lector.leer_archivo().unwrap();
+    let builder = TableroBuilder::new(&casilleros).unwrap();
+    let parcial = builder.crear_tablero_parcial().unwrap();
+    assert!(parcial.verificar_unicidad(2).unwrap().es_unica());
+    assert!(!parcial.verificar_unicidad(3).unwrap().es_unica());
+}
+
+#[test]
+fn resuelve_test_hexagonal() {
+    let path = "test_files/test_hexagonal.txt";
+    let lector = Lector::new(path);
+    let casilleros = lector.leer_archivo().unwrap();
+    let builder = TableroBuilder::new(&casilleros).unwrap();
+    let builder = builder.con_vecindario(Vecindario::Hexagonal);
+    let solucion = builder.crear_tablero().unwrap();
+    let path = std::env::temp_dir().join("buscaminas_solucion_hexagonal.txt");
+    let ruta = path.to_string_lossy();
+    Escritor::new(&ruta).imprimir_item(&solucion).unwrap();
+    let casilleros = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(casilleros, "* 1 . .\n 1 1 1 .\n. 1 * 1\n . 1 1 .\n")
+}
+
+#[test]
+fn resuelve_test_3d() {
+    let path = "test_files/test_3d.txt";
+    let lector = Lector::new(path);
+    let casilleros = lector.leer_archivo().unwrap();
+    let builder = TableroBuilder::new(&casilleros).unwrap();
+    assert!(builder.es_tridimensional());
+    let solucion = builder.crear_tablero_3d().unwrap();
+    let path = std::env::temp_dir().join("buscaminas_solucion_3d.txt");
+    let ruta = path.to_string_lossy();
+    Escritor::new(&ruta).imprimir_item(&solucion).unwrap();
+    let casilleros = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(casilleros, "*21\n221\n111\n\n221\n2*1\n111\n")
+}
+
+#[test]
+fn resuelve_desde_la_entrada_estandar() {
+    let mut hijo = Command::new(env!("CARGO_BIN_EXE_buscaminas"))
+        .arg("-")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    hijo.stdin.take().unwrap().write_all(b"*.\n..\n").unwrap();
+    let salida = hijo.wait_with_output().unwrap();
+    assert!(salida.status.success());
+    assert_eq!(String::from_utf8(salida.stdout).unwrap(), "*1\n11\n");
+}
+
+#[test]
+fn resuelve_en_el_archivo_de_salida() {
+    let path = "test_files/test1.solucion_cli.txt";
+    let salida = Command::new(env!("CARGO_BIN_EXE_buscaminas"))
+        .args(["test_files/test1.txt", "--output", path])
+        .output()
+        .unwrap();
+    assert!(salida.status.success());
+    assert!(salida.stdout.is_empty());
+    let casilleros = Lector::new(path).leer_archivo().unwrap();
+    fs::remove_file(path).unwrap();
+    assert_eq!(casilleros, "1**22*\n13*22*\n");
+}
+
+#[test]
+fn todos_los_subcomandos_muestran_su_ayuda() {
+    let comandos = [
+        "resolver",
+        "generar",
+        "jugar",
+        "validar",
+        "pista",
+        "convertir",
+        "estadisticas",
+    ];
+    for comando in comandos {
+        let salida = Command::new(env!("CARGO_BIN_EXE_buscaminas"))
+            .args([comando, "--help"])
+            .output()
+            .unwrap();
+        assert!(salida.status.success());
+        let ayuda = String::from_utf8(salida.stdout).unwrap();
+        assert!(ayuda.starts_with(&format!("Uso: buscaminas {}", comando)));
+    }
+}
+
+#[test]
+fn validar_finaliza_con_error_si_no_es_unica() {
+    let salida = Command::new(env!("CARGO_BIN_EXE_buscaminas"))
+        .args(["validar", "test_files/test_parcial.txt", "2"])
+        .output()
+        .unwrap();
+    assert!(salida.status.success());
+    let salida = Command::new(env!("CARGO_BIN_EXE_buscaminas"))
+        .args(["validar", "test_files/test_parcial.txt", "3"])
+        .output()
+        .unwrap();
+    assert!(!salida.status.success());
+}
+
+#[test]
+fn generar_y_resolver_en_una_tuberia() {
+    let generado = Command::new(env!("CARGO_BIN_EXE_buscaminas"))
+        .args(["generar", "--ancho", "6", "--largo", "4", "--semilla", "7"])
+        .output()
+        .unwrap();
+    assert!(generado.status.success());
+    let mut hijo = Command::new(env!("CARGO_BIN_EXE_buscaminas"))
+        .args(["resolver", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    hijo.stdin
+        .take()
+        .unwrap()
+        .write_all(&generado.stdout)
+        .unwrap();
+    let resuelto = hijo.wait_with_output().unwrap();
+    let esperado = Command::new(env!("CARGO_BIN_EXE_buscaminas"))
+        .args(["generar", "--ancho", "6", "--largo", "4", "--semilla", "7"])
+        .arg("--resuelto")
+        .output()
+        .unwrap();
+    assert_eq!(resuelto.stdout, esperado.stdout);
+}
+
+#[test]
+fn resuelve_en_lote_con_un_resumen() {
+    let directorio = std::env::temp_dir().join("buscaminas_lote");
+    let salida = Command::new(env!("CARGO_BIN_EXE_buscaminas"))
+        .args(["test_files/test1.txt", "test_files/test_3d.txt"])
+        .arg("test_files/test_malformado.txt")
+        .arg("--directorio")
+        .arg(&directorio)
+        .output()
+        .unwrap();
+    let resumen = String::from_utf8(salida.stdout).unwrap();
+    let test1 = fs::read_to_string(directorio.join("test1.solucion.txt")).unwrap();
+    let test_3d = fs::read_to_string(directorio.join("test_3d.solucion.txt")).unwrap();
+    let malformado = directorio.join("test_malformado.solucion.txt").exists();
+    fs::remove_dir_all(&directorio).unwrap();
+    assert!(!salida.status.success());
+    assert!(resumen.starts_with("Resueltos: 2\nFallidos: 1\n"));
+    assert!(resumen.contains("test_malformado.txt"));
+    assert_eq!(test1, "1**22*\n13*22*\n");
+    assert_eq!(test_3d, "*21\n221\n111\n\n221\n2*1\n111\n");
+    assert!(!malformado);
+}
+
+#[test]
+fn resuelve_en_flujo_como_el_tablero_completo() {
+    let resolver = |archivo: &str, banderas: &[&str]| {
+        let salida = Command::new(env!("CARGO_BIN_EXE_buscaminas"))
+            .args(["resolver", archivo])
+            .args(banderas)
+            .output()
+            .unwrap();
+        assert!(salida.status.success());
+        String::from_utf8(salida.stdout).unwrap()
+    };
+    // En flujo las celdas siempre se alinean a dos caracteres con el vecindario de Moore.
+    for archivo in ["test_files/test1.txt", "test_files/test2.txt"] {
+        let flujo = resolver(archivo, &["--flujo"]);
+        assert_eq!(flujo.replace(' ', ""), resolver(archivo, &[]));
+    }
+    let archivo = "test_files/test_varias_minas.txt";
+    let flujo = resolver(archivo, &["--flujo"]);
+    assert_eq!(flujo, " @  @  @\n @ 16  @\n @  @  @\n");
+    assert_eq!(flujo, resolver(archivo, &[]));
+    let toroidal = Command::new(env!("CARGO_BIN_EXE_buscaminas"))
+        .args(["resolver", "test_files/test1.txt", "--flujo"])
+        .args(["--topologia", "toroidal"])
+        .output()
+        .unwrap();
+    assert!(!toroidal.status.success());
+}
+
+#[test]
+fn validar_informa_la_unicidad_y_rechaza_argumentos_invalidos() {
+    let validar = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_buscaminas"))
+            .arg("validar")
+            .args(args)
+            .output()
+            .unwrap()
+    };
+    let unica = validar(&["test_files/test_parcial.txt", "2"]);
+    let unica = String::from_utf8(unica.stdout).unwrap();
+    assert!(unica.starts_with("El problema tiene solución única:\n"));
+    let ambigua = validar(&["test_files/test_parcial.txt", "3"]);
+    assert_eq!(ambigua.status.code(), Some(1));
+    assert!(ambigua.stderr.is_empty());
+    let ambigua = String::from_utf8(ambigua.stdout).unwrap();
+    assert!(ambigua.starts_with("El problema no tiene solución"));
+    let indeterminada = validar(&["test_files/test_indeterminado.txt", "20"]);
+    assert_eq!(indeterminada.status.code(), Some(2));
+    let indeterminada = String::from_utf8(indeterminada.stdout).unwrap();
+    assert!(indeterminada.starts_with("No se pudo determinar"));
+    for args in [
+        &["test_files/test_parcial.txt"][..],
+        &["test_files/test_parcial.txt", "dos"],
+    ] {
+        let salida = validar(args);
+        assert!(!salida.status.success());
+        assert!(salida.stdout.is_empty());
+        assert!(String::from_utf8(salida.stderr)
+            .unwrap()
+            .contains("ErrorIO"));
+    }
+}
+
+#[test]
+fn convertir_reescribe_con_otros_simbolos() {
+    let convertir = |simbolos: &str| {
+        Command::new(env!("CARGO_BIN_EXE_buscaminas"))
+            .args(["convertir", "test_files/test1.txt", "--a", simbolos])
+            .output()
+            .unwrap()
+    };
+    let salida = convertir("mina=X,espacio=0");
+    assert!(salida.status.success());
+    assert_eq!(
+        String::from_utf8(salida.stdout).unwrap(),
+        "0XX00X\n00X00X\n"
+    );
+    assert!(!convertir("mina=#").status.success());
+}
+
+#[test]
+fn estadisticas_del_tablero_resuelto() {
+    let salida = Command::new(env!("CARGO_BIN_EXE_buscaminas"))
+        .args(["estadisticas", "test_files/test1.txt"])
+        .output()
+        .unwrap();
+    assert!(salida.status.success());
+    let estadisticas = String::from_utf8(salida.stdout).unwrap();
+    let esperado = "Celdas: 12\nMinas: 5\nAnti-minas: 0\nParedes: 0\nEspacios: 7\n\
+                    Espacios sin minas adyacentes: 0\nMáximo de minas adyacentes: 3\n\
+                    Densidad: 41.67%\n";
+    assert_eq!(estadisticas, esperado);
+}
+
+#[test]
+fn generar_rechaza_la_densidad_con_el_primer_click() {
+    let salida = Command::new(env!("CARGO_BIN_EXE_buscaminas"))
+        .args(["generar", "--densidad", "0.2", "--primer-click", "1,1"])
+        .output()
+        .unwrap();
+    assert!(!salida.status.success());
+    assert!(salida.stdout.is_empty());
+}
+
+#[test]
+fn jugar_comienza_con_el_primer_click_revelado() {
+    let jugar = |args: &[&str]| {
+        let mut hijo = Command::new(env!("CARGO_BIN_EXE_buscaminas"))
+            .arg("jugar")
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        hijo.stdin.take().unwrap().write_all(b"q\n").unwrap();
+        hijo.wait_with_output().unwrap()
+    };
+    let generacion = [
+        "--ancho",
+        "5",
+        "--largo",
+        "5",
+        "--minas",
+        "3",
+        "--semilla",
+        "7",
+    ];
+    let click = jugar(&[&generacion[..], &["--primer-click", "2,2"]].concat());
+    assert!(click.status.success());
+    let tablero = String::from_utf8(click.stdout).unwrap();
+    assert_ne!(tablero.lines().nth(2).unwrap().chars().nth(2), Some('?'));
+    let sin_adivinar = jugar(&[&generacion[..], &["--sin-adivinar"]].concat());
+    let tablero = String::from_utf8(sin_adivinar.stdout).unwrap();
+    assert!(!tablero.starts_with('?'));
+    let sin_click = jugar(&generacion);
+    let tablero = String::from_utf8(sin_click.stdout).unwrap();
+    assert!(tablero.lines().all(|fila| fila == "?????"));
+}
+
+#[test]
+fn jugar_rechaza_opciones_de_generacion_con_un_archivo() {
+    for opciones in [
+        &["--minas", "3"][..],
+        &["--sin-adivinar"],
+        &["--ancho", "4"],
+    ] {
+        let salida = Command::new(env!("CARGO_BIN_EXE_buscaminas"))
+            .args(["jugar", "test_files/test1.txt"])
+            .args(opciones)
+            .stdin(Stdio::null())
+            .output()
+            .unwrap();
+        assert!(!salida.status.success());
+        assert!(salida.stdout.is_empty());
+        assert!(String::from_utf8(salida.stderr)
+            .unwrap()
+            .contains("solo puede usarse al generar"));
+    }
+}